@@ -0,0 +1,45 @@
+//! Generates a test for every example fixture in `examples/dayN/`, see `tests/examples.rs`
+
+use std::{env, fmt::Write, fs, path::Path};
+
+const EXAMPLES_DIR: &str = "examples";
+
+fn main() {
+    println!("cargo:rerun-if-changed={EXAMPLES_DIR}");
+
+    let mut fixtures = Vec::new();
+    for entry in fs::read_dir(EXAMPLES_DIR).into_iter().flatten().flatten() {
+        let dir_name = entry.file_name().to_string_lossy().to_string();
+        let Some(day) = dir_name
+            .strip_prefix("day")
+            .and_then(|day| day.parse::<u8>().ok())
+        else {
+            continue;
+        };
+
+        for fixture in fs::read_dir(entry.path()).into_iter().flatten().flatten() {
+            let path = fixture.path();
+            if path.extension().is_some_and(|extension| extension == "txt") {
+                let name = path.file_stem().unwrap().to_string_lossy().to_string();
+                fixtures.push((day, name));
+            }
+        }
+    }
+    fixtures.sort();
+
+    let mut tests = String::new();
+    for (day, name) in fixtures {
+        let test_name: String = name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect();
+        writeln!(
+            tests,
+            "#[test]\nfn day{day}_{test_name}() {{\n    check_fixture({day}, {name:?});\n}}\n"
+        )
+        .unwrap();
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(Path::new(&out_dir).join("examples.rs"), tests).unwrap();
+}
@@ -0,0 +1,53 @@
+use std::{convert::Infallible, str::FromStr};
+
+use derive_more::derive::Display;
+
+/// The result of solving a part, kept typed so answers can be compared by value
+#[derive(Debug, Clone, PartialEq, Eq, Display)]
+pub enum Answer {
+    #[display("{_0}")]
+    Number(i64),
+    #[display("{_0}")]
+    Text(String),
+    /// The part hasn't been solved yet
+    #[display("unsolved")]
+    Unsolved,
+}
+
+macro_rules! impl_from_number {
+    ($($num:ty),*) => {
+        $(
+            impl From<$num> for Answer {
+                fn from(value: $num) -> Self {
+                    Answer::Number(value as i64)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_number!(i32, i64, isize, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    /// Numbers become `Answer::Number` so they compare equal to solved numeric parts
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.parse() {
+            Ok(number) => Answer::Number(number),
+            Err(_) => Answer::Text(s.to_string()),
+        })
+    }
+}
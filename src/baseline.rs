@@ -0,0 +1,198 @@
+//! Benchmark results saved to disk so later runs can be compared against them.
+//!
+//! Baselines live in `./baselines/<name>.tsv` with a line per day and stage holding the median
+//! and standard deviation in nanoseconds.
+
+use std::{collections::BTreeMap, fs, io, str::FromStr};
+
+use derive_more::derive::Display;
+
+use crate::{bench::Stats, AocError};
+
+pub const BASELINE_DIR: &str = "./baselines";
+
+/// Median and standard deviation in nanoseconds keyed by `(day, stage)`
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline(BTreeMap<(u8, String), (f64, f64)>);
+
+impl Baseline {
+    pub fn path(name: &str) -> String {
+        format!("{BASELINE_DIR}/{name}.tsv")
+    }
+
+    pub fn load(name: &str) -> Result<Self, AocError> {
+        let path = Self::path(name);
+        fs::read_to_string(&path)
+            .map_err(|source| AocError::Io { path, source })?
+            .parse()
+    }
+
+    /// Loads the baseline if it exists so saving a few days doesn't wipe out the others
+    pub fn load_or_default(name: &str) -> Result<Self, AocError> {
+        match Self::load(name) {
+            Err(AocError::Io { source, .. }) if source.kind() == io::ErrorKind::NotFound => {
+                Ok(Self::default())
+            }
+            result => result,
+        }
+    }
+
+    pub fn save(&self, name: &str) -> Result<(), AocError> {
+        let path = Self::path(name);
+        fs::create_dir_all(BASELINE_DIR)
+            .and_then(|_| fs::write(&path, self.to_string()))
+            .map_err(|source| AocError::Io { path, source })
+    }
+
+    pub fn insert(&mut self, day: u8, stage: &str, stats: &Stats) {
+        self.0
+            .insert((day, stage.to_string()), (stats.median, stats.std_dev));
+    }
+
+    /// Compares the new median of a stage against this baseline, a change only counts once it's
+    /// more than `threshold` percent and bigger than the noise in both runs
+    pub fn compare(&self, day: u8, stage: &str, stats: &Stats, threshold: f64) -> Option<Change> {
+        let (median, std_dev) = self.0.get(&(day, stage.to_string()))?;
+        // A stage faster than the timer can measure has nothing to take a percentage of
+        if *median <= 0.0 {
+            return Some(Change::Unchanged(0.0));
+        }
+
+        let difference = stats.median - median;
+        let percent = difference / median * 100.0;
+        let noise = std_dev.hypot(stats.std_dev);
+
+        Some(if difference.abs() <= noise {
+            Change::Unchanged(percent)
+        } else if percent > threshold {
+            Change::Regressed(percent)
+        } else if percent < -threshold {
+            Change::Improved(percent)
+        } else {
+            Change::Unchanged(percent)
+        })
+    }
+}
+
+impl std::fmt::Display for Baseline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for ((day, stage), (median, std_dev)) in &self.0 {
+            writeln!(f, "{day}\t{stage}\t{median}\t{std_dev}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Baseline {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut entries = BTreeMap::new();
+        for (line_index, line) in s.lines().enumerate() {
+            let parts = line.split('\t').collect::<Vec<_>>();
+            let [day, stage, median, std_dev] = parts.as_slice() else {
+                return Err(AocError::parse(
+                    line_index,
+                    0,
+                    "expected day, stage, median and std dev separated by tabs",
+                ));
+            };
+
+            let parse = |value: &str| {
+                value
+                    .parse::<f64>()
+                    .map_err(|err| AocError::parse(line_index, 0, format!("'{value}': {err}")))
+            };
+            let day = day
+                .parse()
+                .map_err(|err| AocError::parse(line_index, 0, format!("'{day}': {err}")))?;
+
+            entries.insert((day, stage.to_string()), (parse(median)?, parse(std_dev)?));
+        }
+
+        Ok(Self(entries))
+    }
+}
+
+/// How a stage's median time moved compared to the baseline, holding the percentage change
+#[derive(Debug, Clone, Copy, PartialEq, Display)]
+pub enum Change {
+    #[display("{_0:+.1}% regressed")]
+    Regressed(f64),
+    #[display("{_0:+.1}% improved")]
+    Improved(f64),
+    #[display("{_0:+.1}%")]
+    Unchanged(f64),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(median: f64) -> Stats {
+        noisy_stats(median, 10.0)
+    }
+
+    fn noisy_stats(median: f64, std_dev: f64) -> Stats {
+        Stats {
+            min: median,
+            median,
+            mean: median,
+            std_dev,
+            outliers: 0,
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut baseline = Baseline::default();
+        baseline.insert(6, "parse", &stats(1.0));
+        baseline.insert(6, "part 2", &stats(2.5));
+
+        let parsed: Baseline = baseline.to_string().parse().unwrap();
+        assert_eq!(parsed, baseline);
+    }
+
+    #[test]
+    fn test_compare() {
+        let mut baseline = Baseline::default();
+        baseline.insert(9, "parse", &stats(100.0));
+        baseline.insert(9, "part 1", &stats(1000.0));
+        baseline.insert(9, "part 2", &stats(1000.0));
+
+        assert_eq!(
+            baseline.compare(9, "part 1", &stats(1200.0), 5.0),
+            Some(Change::Regressed(20.0))
+        );
+        assert_eq!(
+            baseline.compare(9, "part 2", &stats(500.0), 5.0),
+            Some(Change::Improved(-50.0))
+        );
+        assert_eq!(
+            baseline.compare(9, "parse", &stats(102.0), 5.0),
+            Some(Change::Unchanged(2.0))
+        );
+        assert_eq!(baseline.compare(10, "parse", &stats(102.0), 5.0), None);
+    }
+
+    #[test]
+    fn test_compare_noise() {
+        let mut baseline = Baseline::default();
+        baseline.insert(9, "part 1", &noisy_stats(1000.0, 300.0));
+        baseline.insert(9, "part 2", &noisy_stats(0.0, 0.0));
+
+        // 20% slower but well within how much the runs vary
+        assert_eq!(
+            baseline.compare(9, "part 1", &noisy_stats(1200.0, 300.0), 5.0),
+            Some(Change::Unchanged(20.0))
+        );
+        assert_eq!(
+            baseline.compare(9, "part 1", &noisy_stats(2000.0, 300.0), 5.0),
+            Some(Change::Regressed(100.0))
+        );
+        assert_eq!(
+            baseline.compare(9, "part 2", &stats(50.0), 5.0),
+            Some(Change::Unchanged(0.0))
+        );
+    }
+}
@@ -0,0 +1,262 @@
+//! Statistical benchmarking of the registered days.
+//!
+//! Each stage (parsing, part 1 and part 2) is run a few times to warm up, then sampled
+//! `iterations` times. Outliers are trimmed using Tukey's fences (anything more than 1.5 times the
+//! interquartile range outside of the middle half) so a single slow run from the OS scheduling
+//! something else doesn't skew the results.
+//!
+//! Results can be saved with `--save-baseline <name>` and compared against later with
+//! `--baseline <name>`, see the `baseline` module.
+
+use std::time::Duration;
+
+use crate::{
+    baseline::Baseline,
+    input::InputSource,
+    isolate::isolate,
+    runner::{print_aligned, Parts},
+    AocError, Solution,
+};
+
+pub const STAGES: [&str; 3] = ["parse", "part 1", "part 2"];
+
+/// Raw timings for each of the `STAGES`
+pub type Samples = [Vec<Duration>; 3];
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchOptions {
+    pub iterations: usize,
+    pub warmup: usize,
+    /// Name of the baseline to save the results to
+    pub save_baseline: Option<String>,
+    /// Name of the baseline to compare the results against
+    pub baseline: Option<String>,
+    /// Percentage the median has to move by before it counts as a regression or improvement
+    pub threshold: f64,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        Self {
+            iterations: 10,
+            warmup: 2,
+            save_baseline: None,
+            baseline: None,
+            threshold: 5.0,
+        }
+    }
+}
+
+/// Summary of a set of samples after outliers have been trimmed, all values are in nanoseconds
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub std_dev: f64,
+    /// How many samples were thrown away as outliers
+    pub outliers: usize,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos: Vec<f64> = samples.iter().map(|d| d.as_nanos() as f64).collect();
+        nanos.sort_by(f64::total_cmp);
+
+        let q1 = percentile(&nanos, 0.25);
+        let q3 = percentile(&nanos, 0.75);
+        let fence = (q3 - q1) * 1.5;
+        let trimmed: Vec<f64> = nanos
+            .iter()
+            .copied()
+            .filter(|nanos| (q1 - fence..=q3 + fence).contains(nanos))
+            .collect();
+
+        let mean = trimmed.iter().sum::<f64>() / trimmed.len() as f64;
+        let variance = match trimmed.len() {
+            0 | 1 => 0.0,
+            len => trimmed.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (len - 1) as f64,
+        };
+
+        Self {
+            min: trimmed.first().copied().unwrap_or_default(),
+            median: percentile(&trimmed, 0.5),
+            mean,
+            std_dev: variance.sqrt(),
+            outliers: nanos.len() - trimmed.len(),
+        }
+    }
+}
+
+/// Linearly interpolated percentile of already sorted values
+fn percentile(sorted: &[f64], percent: f64) -> f64 {
+    let Some(last) = sorted.len().checked_sub(1) else {
+        return 0.0;
+    };
+    let rank = percent * last as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+/// Formats nanoseconds using whichever unit keeps the number readable
+pub fn format_nanos(nanos: f64) -> String {
+    if nanos < 1_000.0 {
+        format!("{nanos:.0}ns")
+    } else if nanos < 1_000_000.0 {
+        format!("{:.2}µs", nanos / 1_000.0)
+    } else if nanos < 1_000_000_000.0 {
+        format!("{:.2}ms", nanos / 1_000_000.0)
+    } else {
+        format!("{:.2}s", nanos / 1_000_000_000.0)
+    }
+}
+
+/// Warms up then samples every selected stage of a day
+pub fn bench_day(
+    day: &dyn Solution,
+    options: &BenchOptions,
+    input: &InputSource,
+    parts: Parts,
+) -> Result<Vec<(&'static str, Stats)>, AocError> {
+    let input = input.read(day)?;
+
+    if options.warmup > 0 {
+        isolate(|| day.sample(&input, options.warmup, parts))?;
+    }
+    let samples = isolate(|| day.sample(&input, options.iterations, parts))?;
+
+    Ok(STAGES
+        .into_iter()
+        .zip(samples)
+        .filter(|(_, samples)| !samples.is_empty())
+        .map(|(stage, samples)| (stage, Stats::from_samples(&samples)))
+        .collect())
+}
+
+/// Benchmarks each day printing a table of the results, returns false if any day failed
+pub fn bench(
+    days: &[&dyn Solution],
+    options: &BenchOptions,
+    input: &InputSource,
+    parts: Parts,
+) -> bool {
+    let compare_to = match options.baseline.as_deref().map(Baseline::load).transpose() {
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("Failed to load baseline: {err}");
+            return false;
+        }
+    };
+    let mut to_save = match options
+        .save_baseline
+        .as_deref()
+        .map(Baseline::load_or_default)
+        .transpose()
+    {
+        Ok(baseline) => baseline,
+        Err(err) => {
+            eprintln!("Failed to load baseline: {err}");
+            return false;
+        }
+    };
+
+    let mut rows = Vec::new();
+    let mut succeeded = true;
+
+    for day in days {
+        match bench_day(*day, options, input, parts) {
+            Ok(stages) => {
+                for (stage, stats) in stages {
+                    if let Some(baseline) = &mut to_save {
+                        baseline.insert(day.day(), stage, &stats);
+                    }
+
+                    let mut row = vec![
+                        day.day().to_string(),
+                        stage.to_string(),
+                        format_nanos(stats.min),
+                        format_nanos(stats.median),
+                        format_nanos(stats.mean),
+                        format_nanos(stats.std_dev),
+                        stats.outliers.to_string(),
+                    ];
+                    if let Some(baseline) = &compare_to {
+                        row.push(
+                            baseline
+                                .compare(day.day(), stage, &stats, options.threshold)
+                                .map(|change| change.to_string())
+                                .unwrap_or_else(|| "new".to_string()),
+                        );
+                    }
+                    rows.push(row);
+                }
+            }
+            Err(err) => {
+                succeeded = false;
+                rows.push(vec![day.day().to_string(), err.to_string()]);
+            }
+        }
+    }
+
+    println!(
+        "{} iterations after {} warmup runs",
+        options.iterations, options.warmup
+    );
+    let mut header = vec![
+        "Day", "Stage", "Min", "Median", "Mean", "Std Dev", "Outliers",
+    ];
+    if let Some(name) = &options.baseline {
+        header.push(name);
+    }
+    print_aligned(&header, &rows);
+
+    if let (Some(baseline), Some(name)) = (to_save, &options.save_baseline) {
+        match baseline.save(name) {
+            Ok(()) => println!("Saved baseline to {}", Baseline::path(name)),
+            Err(err) => {
+                eprintln!("Failed to save baseline: {err}");
+                succeeded = false;
+            }
+        }
+    }
+
+    succeeded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_micros(*x)).collect()
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&micros(&[4, 2, 3, 5, 1]));
+
+        assert_eq!(stats.min, 1_000.0);
+        assert_eq!(stats.median, 3_000.0);
+        assert_eq!(stats.mean, 3_000.0);
+        assert_eq!(stats.outliers, 0);
+        assert!((stats.std_dev - 1_581.14).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_stats_trims_outliers() {
+        let stats = Stats::from_samples(&micros(&[10, 11, 10, 12, 11, 10, 500]));
+
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.min, 10_000.0);
+        assert_eq!(stats.median, 10_500.0);
+    }
+
+    #[test]
+    fn test_format_nanos() {
+        assert_eq!(format_nanos(850.0), "850ns");
+        assert_eq!(format_nanos(12_346.0), "12.35µs");
+        assert_eq!(format_nanos(2_500_000.0), "2.50ms");
+    }
+}
@@ -0,0 +1,192 @@
+//! Answers saved to disk so slow days don't have to be solved again on every run.
+//!
+//! The cache lives in `./.cache/answers.tsv`. Its first line is a hash of the binary that wrote
+//! it, since any change to the code changes the binary, the whole cache is thrown away when it
+//! doesn't match. After that there's a line per answer holding the day, part, a hash of the input
+//! it was solved for and the answer.
+
+use std::{collections::BTreeMap, env, fs, io, path::Path, str::FromStr};
+
+use crate::{Answer, AocError};
+
+pub const CACHE_PATH: &str = "./.cache/answers.tsv";
+
+/// Answers keyed by `(day, part, input hash)` for the binary with the hash `version`
+#[derive(Debug, Default, PartialEq)]
+pub struct AnswerCache {
+    version: u64,
+    answers: BTreeMap<(u8, u8, u64), Answer>,
+}
+
+impl AnswerCache {
+    pub fn new(version: u64) -> Self {
+        Self {
+            version,
+            answers: BTreeMap::new(),
+        }
+    }
+
+    /// Hash of the running binary, used as the version of every solution in it
+    pub fn binary_version() -> Result<u64, AocError> {
+        let path = env::current_exe().map_err(|source| AocError::Io {
+            path: "<current executable>".to_string(),
+            source,
+        })?;
+        let binary = fs::read(&path).map_err(|source| AocError::Io {
+            path: path.to_string_lossy().into_owned(),
+            source,
+        })?;
+        Ok(hash(&binary))
+    }
+
+    /// Loads the cache for `version`, starting from an empty one if the file is missing, was
+    /// written by another binary or can't be parsed, since it can always be rebuilt
+    pub fn load(path: &str, version: u64) -> Result<Self, AocError> {
+        let cache: Self = match fs::read_to_string(path) {
+            Ok(contents) => contents.parse().unwrap_or_else(|_| Self::new(version)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Self::new(version),
+            Err(source) => {
+                return Err(AocError::Io {
+                    path: path.to_string(),
+                    source,
+                })
+            }
+        };
+
+        if cache.version != version {
+            return Ok(Self::new(version));
+        }
+        Ok(cache)
+    }
+
+    pub fn save(&self, path: &str) -> Result<(), AocError> {
+        let io_error = |source| AocError::Io {
+            path: path.to_string(),
+            source,
+        };
+        if let Some(parent) = Path::new(path).parent() {
+            fs::create_dir_all(parent).map_err(io_error)?;
+        }
+        fs::write(path, self.to_string()).map_err(io_error)
+    }
+
+    pub fn get(&self, day: u8, part: u8, input_hash: u64) -> Option<&Answer> {
+        self.answers.get(&(day, part, input_hash))
+    }
+
+    /// Stores a solved answer, unsolved parts and answers that wouldn't fit on a line aren't kept
+    pub fn insert(&mut self, day: u8, part: u8, input_hash: u64, answer: &Answer) {
+        let storable = match answer {
+            Answer::Number(_) => true,
+            Answer::Text(text) => !text.is_empty() && !text.contains(['\t', '\n', '\r']),
+            Answer::Unsolved => false,
+        };
+        if storable {
+            self.answers.insert((day, part, input_hash), answer.clone());
+        }
+    }
+}
+
+/// FNV-1a, which is stable across Rust versions unlike the standard library's hasher
+pub fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+impl std::fmt::Display for AnswerCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:016x}", self.version)?;
+        for ((day, part, input_hash), answer) in &self.answers {
+            writeln!(f, "{day}\t{part}\t{input_hash:016x}\t{answer}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for AnswerCache {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_hash = |line_index, value: &str| {
+            u64::from_str_radix(value, 16)
+                .map_err(|err| AocError::parse(line_index, 0, format!("'{value}': {err}")))
+        };
+
+        let mut lines = s.lines().enumerate();
+        let Some((_, version)) = lines.next() else {
+            return Ok(Self::default());
+        };
+        let mut cache = Self::new(parse_hash(0, version)?);
+
+        for (line_index, line) in lines {
+            let parts = line.split('\t').collect::<Vec<_>>();
+            let [day, part, input_hash, answer] = parts.as_slice() else {
+                return Err(AocError::parse(
+                    line_index,
+                    0,
+                    "expected day, part, input hash and answer separated by tabs",
+                ));
+            };
+
+            let parse_number = |value: &str| {
+                value
+                    .parse::<u8>()
+                    .map_err(|err| AocError::parse(line_index, 0, format!("'{value}': {err}")))
+            };
+            let Ok(answer) = answer.parse();
+            cache.answers.insert(
+                (
+                    parse_number(day)?,
+                    parse_number(part)?,
+                    parse_hash(line_index, input_hash)?,
+                ),
+                answer,
+            );
+        }
+
+        Ok(cache)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let mut cache = AnswerCache::new(hash(b"binary"));
+        cache.insert(6, 2, hash(b"input"), &Answer::Number(1770));
+        cache.insert(9, 1, hash(b"input"), &"abc".into());
+        cache.insert(9, 2, hash(b"input"), &Answer::Unsolved);
+        cache.insert(10, 1, hash(b"input"), &"two\nlines".into());
+
+        let parsed: AnswerCache = cache.to_string().parse().unwrap();
+        assert_eq!(parsed, cache);
+        assert_eq!(
+            parsed.get(6, 2, hash(b"input")),
+            Some(&Answer::Number(1770))
+        );
+        assert_eq!(parsed.get(6, 2, hash(b"other input")), None);
+        assert_eq!(parsed.get(9, 2, hash(b"input")), None);
+        assert_eq!(parsed.get(10, 1, hash(b"input")), None);
+    }
+
+    #[test]
+    fn test_load_unparsable() {
+        let path = env::temp_dir().join(format!("aoc-cache-test-{}.tsv", std::process::id()));
+        let path = path.to_string_lossy();
+        fs::write(&*path, "not a hash\n6\t2").unwrap();
+
+        let cache = AnswerCache::load(&path, hash(b"binary"));
+        fs::remove_file(&*path).unwrap();
+        assert_eq!(cache.unwrap(), AnswerCache::new(hash(b"binary")));
+    }
+
+    #[test]
+    fn test_hash() {
+        assert_eq!(hash(b""), 0xcbf29ce484222325);
+        assert_eq!(hash(b"a"), 0xaf63dc4c8601ec8c);
+        assert_ne!(hash(b"ab"), hash(b"ba"));
+    }
+}
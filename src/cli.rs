@@ -0,0 +1,538 @@
+use std::{str::FromStr, time::Duration};
+
+use aoc::{
+    bench::BenchOptions,
+    external::ExternalCommand,
+    input::InputSource,
+    output::Format,
+    runner::{DaySelection, Parts},
+};
+
+pub const USAGE: &str = "Usage:
+  aoc <day|all|start..end>         Run the selected days
+      --format <text|json|csv>     How to print the results (default text)
+      --cached                     Reuse answers cached for the same code and input
+      --refresh                    Solve again and update the cached answers
+  aoc verify [day|all|start..end]  Check answers against answers.toml
+  aoc bench <day|all|start..end>   Benchmark the selected days
+      --iterations <n>             Number of timed runs (default 10)
+      --warmup <n>                 Untimed runs before timing (default 2)
+      --save-baseline <name>       Save the results as a baseline
+      --baseline <name>            Compare the results against a saved baseline
+      --threshold <percent>        Change needed to flag a regression (default 5)
+  aoc compare <day|all|start..end> Check the Rust answers agree with another language's
+      --command <command>          Command to run per day with {day} and {input} filled in
+                                   (default `nu ./nu/runner.nu {day}`)
+  aoc status                       Print the table of completed days from answers.toml
+      --readme <path>              Also replace the table between the status markers in a README
+  aoc new <day>                    Create the source file, input and example fixture for a day
+  aoc garden <svg|ppm|report>      Draw day 12's garden regions and fences, or list what each
+                                   region's fence costs
+      --labels                     Label each region with its area, perimeter and sides (svg only)
+      --format <text|json>         How to print the report (default text)
+      --output <path>              File to write to instead of stdout
+
+Every command also takes:
+      --part <1|2>                 Only run one part, `aoc 12.2` is short for `aoc 12 --part 2`
+      --timeout <duration>         Give up on a part after this long, like 30s, 500ms or 2m
+                                   (not supported by bench)
+      --input <path|dir|->         Input file for a single day, a directory of dayN.txt
+                                   files or `-` to read a single day's input from stdin
+      --input-text <input>         Use the given text as a single day's input
+      --solver <name>              Run, verify or bench a solver from solvers.toml instead of
+                                   the Rust days
+The input directory defaults to ./inputs and can be changed with AOC_INPUT_DIR";
+
+/// Parsed command line arguments
+#[derive(Debug, PartialEq)]
+pub struct Cli {
+    pub command: Command,
+    pub input: InputSource,
+    pub parts: Parts,
+    pub timeout: Option<Duration>,
+    /// Name of the external solver to use in place of the Rust days
+    pub solver: Option<String>,
+}
+
+/// What the user asked the runner to do
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(DaySelection, RunOptions),
+    Verify(DaySelection),
+    Bench(DaySelection, BenchOptions),
+    Compare(DaySelection, CompareOptions),
+    Status(StatusOptions),
+    New(u8),
+    Garden(GardenOptions),
+}
+
+impl Cli {
+    /// Parses the arguments after the program name
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut positional = Vec::new();
+        let mut flags = Vec::new();
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.strip_prefix("--") {
+                // Switches are on just by being there
+                Some(flag) if SWITCHES.contains(&flag) => flags.push((flag, "")),
+                Some(flag) => {
+                    let value = args
+                        .next()
+                        .ok_or_else(|| format!("Missing value for --{flag}"))?;
+                    flags.push((flag, value.as_str()));
+                }
+                None => positional.push(arg.as_str()),
+            }
+        }
+
+        // `12.2` selects part 2 of day 12, ranges like `3..8` are left alone
+        let mut parts = Parts::Both;
+        if let Some(selection) = positional.last_mut() {
+            if let Some((day, part)) = selection
+                .split_once('.')
+                .filter(|_| !selection.contains(".."))
+            {
+                parts = part.parse()?;
+                *selection = day;
+            }
+        }
+
+        let mut input = InputSource::Default;
+        let mut timeout = None;
+        let mut solver = None;
+        let mut command_flags = Vec::new();
+        for (flag, value) in flags {
+            match flag {
+                "input" => input = InputSource::from_flag(value),
+                "input-text" => input = InputSource::Inline(value.to_string()),
+                "part" => match (parts, value.parse()?) {
+                    (Parts::Only(selected), Parts::Only(part)) if selected != part => {
+                        return Err(format!("Part {selected} was already selected"));
+                    }
+                    (_, part) => parts = part,
+                },
+                "timeout" => timeout = Some(parse_duration(value)?),
+                "solver" => solver = Some(value.to_string()),
+                _ => command_flags.push((flag, value)),
+            }
+        }
+
+        let command = Command::from_parts(&positional, command_flags)?;
+        // The garden is always day 12's
+        let single_day = matches!(command, Command::Garden(_))
+            || matches!(command.selection(), Some(DaySelection::Single(_)));
+        if input.is_single_input() && !single_day {
+            return Err(
+                "A single input file, stdin or --input-text can only be used with one day \
+                 (pass a directory to --input for several days)"
+                    .to_string(),
+            );
+        }
+
+        if solver.is_some()
+            && matches!(&command, Command::Run(_, options) if options.cache != CacheMode::Off)
+        {
+            return Err(
+                "--cached and --refresh can't be used with --solver, the cache only notices \
+                 changes to the Rust code"
+                    .to_string(),
+            );
+        }
+
+        if timeout.is_some() && matches!(command, Command::Bench(..)) {
+            return Err("--timeout can't be used with bench".to_string());
+        }
+
+        if solver.is_some()
+            && !matches!(
+                command,
+                Command::Run(..) | Command::Verify(_) | Command::Bench(..)
+            )
+        {
+            return Err("--solver can only be used with run, verify and bench".to_string());
+        }
+
+        Ok(Self {
+            command,
+            input,
+            parts,
+            timeout,
+            solver,
+        })
+    }
+}
+
+impl Command {
+    fn from_parts(positional: &[&str], mut flags: Vec<(&str, &str)>) -> Result<Self, String> {
+        let command = match positional {
+            ["new", day] => match day.parse() {
+                Ok(day @ 1..=25) => Command::New(day),
+                _ => return Err(format!("Invalid day '{day}', expected 1 to 25")),
+            },
+            ["compare", selection] => {
+                let mut options = CompareOptions::default();
+                for (flag, value) in flags.drain(..) {
+                    match flag {
+                        "command" => options.command = value.parse()?,
+                        _ => return Err(format!("Unknown flag --{flag} for compare\n{USAGE}")),
+                    }
+                }
+                Command::Compare(selection.parse()?, options)
+            }
+            ["garden", output] => {
+                let mut options = GardenOptions {
+                    output: output.parse()?,
+                    ..Default::default()
+                };
+                for (flag, value) in flags.drain(..) {
+                    match flag {
+                        "labels" => options.labels = true,
+                        "format" => options.format = value.parse()?,
+                        "output" => options.path = Some(value.to_string()),
+                        _ => return Err(format!("Unknown flag --{flag} for garden\n{USAGE}")),
+                    }
+                }
+                if options.labels && options.output != GardenOutput::Svg {
+                    return Err("--labels can only be used with svg".to_string());
+                }
+                match (options.output, options.format) {
+                    (_, Format::Text) | (GardenOutput::Report, Format::Json) => {}
+                    (GardenOutput::Report, Format::Csv) => {
+                        return Err("The report can only be printed as text or json".to_string())
+                    }
+                    _ => return Err("--format can only be used with report".to_string()),
+                }
+                Command::Garden(options)
+            }
+            ["status"] => {
+                let mut options = StatusOptions::default();
+                for (flag, value) in flags.drain(..) {
+                    match flag {
+                        "readme" => options.readme = Some(value.to_string()),
+                        _ => return Err(format!("Unknown flag --{flag} for status\n{USAGE}")),
+                    }
+                }
+                Command::Status(options)
+            }
+            ["verify"] => Command::Verify(DaySelection::All),
+            ["verify", selection] => Command::Verify(selection.parse()?),
+            ["bench", selection] => {
+                let mut options = BenchOptions::default();
+                for (flag, value) in flags.drain(..) {
+                    match flag {
+                        "iterations" => options.iterations = parse_flag(flag, value)?,
+                        "warmup" => options.warmup = parse_flag(flag, value)?,
+                        "save-baseline" => options.save_baseline = Some(value.to_string()),
+                        "baseline" => options.baseline = Some(value.to_string()),
+                        "threshold" => options.threshold = parse_flag(flag, value)?,
+                        _ => return Err(format!("Unknown flag --{flag} for bench\n{USAGE}")),
+                    }
+                }
+                if options.iterations == 0 {
+                    return Err("--iterations has to be at least 1".to_string());
+                }
+                Command::Bench(selection.parse()?, options)
+            }
+            [selection] => {
+                let mut options = RunOptions::default();
+                for (flag, value) in flags.drain(..) {
+                    match flag {
+                        "format" => options.format = value.parse()?,
+                        "cached" if options.cache == CacheMode::Off => {
+                            options.cache = CacheMode::Use
+                        }
+                        "cached" => {}
+                        "refresh" => options.cache = CacheMode::Refresh,
+                        _ => return Err(format!("Unknown flag --{flag}\n{USAGE}")),
+                    }
+                }
+                Command::Run(selection.parse()?, options)
+            }
+            _ => return Err(USAGE.to_string()),
+        };
+
+        if let Some((flag, _)) = flags.first() {
+            return Err(format!("Unknown flag --{flag}\n{USAGE}"));
+        }
+
+        Ok(command)
+    }
+
+    pub fn selection(&self) -> Option<&DaySelection> {
+        match self {
+            Command::Run(selection, _)
+            | Command::Verify(selection)
+            | Command::Bench(selection, _)
+            | Command::Compare(selection, _) => Some(selection),
+            Command::Status(_) | Command::New(_) | Command::Garden(_) => None,
+        }
+    }
+}
+
+/// Flags that don't take a value
+const SWITCHES: &[&str] = &["cached", "refresh", "labels"];
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RunOptions {
+    pub format: Format,
+    pub cache: CacheMode,
+}
+
+/// Whether `aoc <days>` uses the answer cache
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CacheMode {
+    #[default]
+    Off,
+    /// `--cached`, reuses cached answers and caches new ones
+    Use,
+    /// `--refresh`, solves every day again and caches the answers
+    Refresh,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompareOptions {
+    pub command: ExternalCommand,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StatusOptions {
+    /// README to rewrite the status table of
+    pub readme: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GardenOptions {
+    pub output: GardenOutput,
+    /// Label each region in the SVG
+    pub labels: bool,
+    /// How to print the report
+    pub format: Format,
+    /// Where to write the output, stdout if not given
+    pub path: Option<String>,
+}
+
+/// What `aoc garden` outputs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum GardenOutput {
+    #[default]
+    Svg,
+    Ppm,
+    /// The cost of every region's fence
+    Report,
+}
+
+impl FromStr for GardenOutput {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "svg" => Ok(GardenOutput::Svg),
+            "ppm" => Ok(GardenOutput::Ppm),
+            "report" => Ok(GardenOutput::Report),
+            _ => Err(format!(
+                "Invalid garden output '{s}', expected svg, ppm or report"
+            )),
+        }
+    }
+}
+
+fn parse_flag<T: FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value '{value}' for --{flag}"))
+}
+
+/// Parses durations like `30s`, `500ms`, `1.5m`, a number without a unit is in seconds
+fn parse_duration(value: &str) -> Result<Duration, String> {
+    let (number, seconds_per_unit) = if let Some(number) = value.strip_suffix("ms") {
+        (number, 0.001)
+    } else if let Some(number) = value.strip_suffix('s') {
+        (number, 1.0)
+    } else if let Some(number) = value.strip_suffix('m') {
+        (number, 60.0)
+    } else {
+        (value, 1.0)
+    };
+
+    number
+        .parse::<f64>()
+        .ok()
+        .and_then(|number| Duration::try_from_secs_f64(number * seconds_per_unit).ok())
+        .filter(|duration| !duration.is_zero())
+        .ok_or_else(|| format!("Invalid duration '{value}', expected something like 30s or 500ms"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    fn command(arguments: &[&str]) -> Result<Command, String> {
+        Cli::from_args(&args(arguments)).map(|cli| cli.command)
+    }
+
+    #[test]
+    fn test_from_args() {
+        assert_eq!(
+            command(&["3"]),
+            Ok(Command::Run(DaySelection::Single(3), RunOptions::default()))
+        );
+        assert_eq!(command(&["verify"]), Ok(Command::Verify(DaySelection::All)));
+        assert_eq!(
+            command(&["verify", "2..4"]),
+            Ok(Command::Verify(DaySelection::Range(2..=4)))
+        );
+        assert_eq!(
+            command(&["all", "--format", "json"]),
+            Ok(Command::Run(
+                DaySelection::All,
+                RunOptions {
+                    format: Format::Json,
+                    ..Default::default()
+                }
+            ))
+        );
+        assert_eq!(command(&["new", "13"]), Ok(Command::New(13)));
+        assert_eq!(
+            command(&["compare", "1..4", "--command", "python3 solve.py {day}"]),
+            Ok(Command::Compare(
+                DaySelection::Range(1..=4),
+                CompareOptions {
+                    command: "python3 solve.py {day}".parse().unwrap()
+                }
+            ))
+        );
+        assert_eq!(
+            command(&["status", "--readme", "README.md"]),
+            Ok(Command::Status(StatusOptions {
+                readme: Some("README.md".into())
+            }))
+        );
+        assert_eq!(
+            command(&["garden", "svg", "--labels", "--output", "garden.svg"]),
+            Ok(Command::Garden(GardenOptions {
+                output: GardenOutput::Svg,
+                labels: true,
+                format: Format::Text,
+                path: Some("garden.svg".into())
+            }))
+        );
+        assert_eq!(
+            command(&["garden", "report", "--format", "json"]),
+            Ok(Command::Garden(GardenOptions {
+                output: GardenOutput::Report,
+                format: Format::Json,
+                ..Default::default()
+            }))
+        );
+        assert!(command(&["garden", "ppm", "--labels"]).is_err());
+        assert!(command(&["garden", "svg", "--format", "json"]).is_err());
+        assert!(command(&["garden", "report", "--format", "csv"]).is_err());
+        assert!(command(&["new", "26"]).is_err());
+        assert!(command(&[]).is_err());
+    }
+
+    #[test]
+    fn test_bench_args() {
+        assert_eq!(
+            command(&["bench", "all", "--iterations", "50"]),
+            Ok(Command::Bench(
+                DaySelection::All,
+                BenchOptions {
+                    iterations: 50,
+                    ..Default::default()
+                }
+            ))
+        );
+        assert!(command(&["bench", "6", "--iterations", "x"]).is_err());
+        assert!(command(&["6", "--iterations", "5"]).is_err());
+    }
+
+    #[test]
+    fn test_input_args() {
+        let cli = Cli::from_args(&args(&["9", "--input", "-"])).unwrap();
+        assert_eq!(cli.input, InputSource::Stdin);
+
+        let cli = Cli::from_args(&args(&["verify", "3", "--input-text", "1 2"])).unwrap();
+        assert_eq!(cli.input, InputSource::Inline("1 2".into()));
+
+        assert!(Cli::from_args(&args(&["all", "--input", "stress.txt"])).is_err());
+    }
+
+    #[test]
+    fn test_part_args() {
+        let cli = Cli::from_args(&args(&["12.2"])).unwrap();
+        assert_eq!(
+            cli.command,
+            Command::Run(DaySelection::Single(12), RunOptions::default())
+        );
+        assert_eq!(cli.parts, Parts::Only(2));
+
+        let cli = Cli::from_args(&args(&["verify", "3..5", "--part", "1"])).unwrap();
+        assert_eq!(cli.command, Command::Verify(DaySelection::Range(3..=5)));
+        assert_eq!(cli.parts, Parts::Only(1));
+
+        let cli = Cli::from_args(&args(&["all"])).unwrap();
+        assert_eq!(cli.parts, Parts::Both);
+
+        assert!(Cli::from_args(&args(&["12.3"])).is_err());
+        assert!(Cli::from_args(&args(&["12.1", "--part", "2"])).is_err());
+    }
+
+    #[test]
+    fn test_cache_args() {
+        let cache = |arguments: &[&str]| match command(arguments) {
+            Ok(Command::Run(_, options)) => Ok(options.cache),
+            other => Err(format!("{other:?}")),
+        };
+
+        assert_eq!(cache(&["all"]), Ok(CacheMode::Off));
+        assert_eq!(cache(&["all", "--cached"]), Ok(CacheMode::Use));
+        assert_eq!(
+            cache(&["--refresh", "all", "--cached"]),
+            Ok(CacheMode::Refresh)
+        );
+        assert_eq!(
+            command(&["all", "--cached", "--format", "json"]),
+            Ok(Command::Run(
+                DaySelection::All,
+                RunOptions {
+                    format: Format::Json,
+                    cache: CacheMode::Use
+                }
+            ))
+        );
+
+        assert!(command(&["verify", "--cached"]).is_err());
+        assert!(command(&["all", "--cached", "--solver", "python"]).is_err());
+    }
+
+    #[test]
+    fn test_solver_args() {
+        let cli = Cli::from_args(&args(&["verify", "--solver", "python"])).unwrap();
+        assert_eq!(cli.solver, Some("python".into()));
+        assert_eq!(Cli::from_args(&args(&["all"])).unwrap().solver, None);
+
+        assert!(Cli::from_args(&args(&["status", "--solver", "python"])).is_err());
+        assert!(Cli::from_args(&args(&["compare", "1", "--solver", "python"])).is_err());
+    }
+
+    #[test]
+    fn test_timeout_args() {
+        let cli = Cli::from_args(&args(&["all", "--timeout", "30s"])).unwrap();
+        assert_eq!(cli.timeout, Some(Duration::from_secs(30)));
+        assert!(Cli::from_args(&args(&["bench", "6", "--timeout", "1s"])).is_err());
+
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("1.5m"), Ok(Duration::from_secs(90)));
+        assert_eq!(parse_duration("2"), Ok(Duration::from_secs(2)));
+        assert!(parse_duration("0s").is_err());
+        assert!(parse_duration("-1s").is_err());
+        assert!(parse_duration("soon").is_err());
+    }
+}
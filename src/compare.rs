@@ -0,0 +1,200 @@
+//! Runs another language's solutions and checks they agree with the Rust ones.
+//!
+//! The external command is run once per day, with `{day}` and `{input}` in its arguments replaced
+//! by the day number and the path of the input file. It has to print a line per part the same way
+//! the Nushell runner does, the timing is optional:
+//!
+//! ```text
+//! Nushell Day 1 results:
+//!   Part1: 11 in 0.52 ms
+//!   Part2: 31 in 1.2 ms
+//! ```
+//!
+//! Parts the command doesn't print, or prints without an answer, haven't been solved in that
+//! language yet so they're shown as missing rather than counted as a disagreement.
+
+use std::{env, time::Duration};
+
+use crate::{
+    external::{parse_time, ExternalCommand, ExternalPart},
+    input::{InputSource, INPUT_DIR_VAR},
+    runner::{print_aligned, DayReport},
+    Answer, AocError,
+};
+
+/// Makes sure the command solves the same input as Rust, a command without `{input}` always reads
+/// the default `./inputs` so it can't be used with `--input` or `AOC_INPUT_DIR`
+pub fn check_input(command: &ExternalCommand, input: &InputSource) -> Result<(), AocError> {
+    let default_input = *input == InputSource::Default && env::var_os(INPUT_DIR_VAR).is_none();
+    if default_input || command.takes_input() {
+        return Ok(());
+    }
+
+    Err(AocError::Unsupported(format!(
+        "{} would read its own input instead of the one given, add {{input}} to the command",
+        command.name()
+    )))
+}
+
+/// Picks the `PartN: answer in time` lines out of an external solution's output
+pub fn parse_output(output: &str) -> [Option<ExternalPart>; 2] {
+    let mut parts = [None, None];
+
+    for line in output.lines() {
+        let Some(rest) = line.trim().strip_prefix("Part") else {
+            continue;
+        };
+        let Some((number, rest)) = rest.split_once(':') else {
+            continue;
+        };
+        let index = match number.trim() {
+            "1" => 0,
+            "2" => 1,
+            _ => continue,
+        };
+
+        let (answer, time) = match rest.rsplit_once(" in ") {
+            Some((answer, time)) => match parse_time(time) {
+                Some(time) => (answer, Some(time)),
+                None => (rest, None),
+            },
+            None => (rest, None),
+        };
+        let answer = match answer.trim() {
+            "" => Answer::Unsolved,
+            answer => {
+                let Ok(answer) = answer.parse();
+                answer
+            }
+        };
+
+        parts[index] = Some(ExternalPart { answer, time });
+    }
+
+    parts
+}
+
+/// Runs the external command for each reported day and prints a table comparing the answers,
+/// returns false if any answers disagree or the command failed, parts the command has no answer
+/// for are skipped
+pub fn compare(reports: &[DayReport], command: &ExternalCommand, input: &InputSource) -> bool {
+    let mut rows = Vec::new();
+    let mut agreed = true;
+
+    for report in reports {
+        let external = command
+            .run(report.day, input.path(report.day).as_deref(), "")
+            .map(|(output, wall_time)| (parse_output(&output), wall_time));
+
+        for part in report.parts.iter() {
+            let rust = match &report.solved {
+                Ok(solved) => match &solved.parts[part as usize - 1] {
+                    Some(part) => part
+                        .answer
+                        .as_ref()
+                        .map(|answer| (answer.clone(), part.time))
+                        .map_err(AocError::to_string),
+                    None => continue,
+                },
+                Err(err) => Err(err.to_string()),
+            };
+            let other = match &external {
+                Ok((parts, wall_time)) => match &parts[part as usize - 1] {
+                    // Without a reported time the whole command's run time is the best there is
+                    Some(other) => Ok((other.answer.clone(), other.time.unwrap_or(*wall_time))),
+                    None => Ok((Answer::Unsolved, *wall_time)),
+                },
+                Err(err) => Err(err.to_string()),
+            };
+
+            let agree = match (&rust, &other) {
+                (_, Ok((Answer::Unsolved, _))) => "missing",
+                (Ok((rust, _)), Ok((other, _))) if rust == other && *rust != Answer::Unsolved => {
+                    "yes"
+                }
+                _ => "NO",
+            };
+            agreed &= agree != "NO";
+
+            let cells = |result: Result<(Answer, Duration), String>| match result {
+                Ok((answer, time)) => [answer.to_string(), format!("{time:.2?}")],
+                Err(err) => [err, String::new()],
+            };
+            let mut row = vec![report.day.to_string(), part.to_string()];
+            row.extend(cells(rust));
+            row.extend(cells(other));
+            row.push(agree.to_string());
+            rows.push(row);
+        }
+    }
+
+    print_aligned(
+        &[
+            "Day",
+            "Part",
+            "Rust",
+            "Time",
+            command.name(),
+            "Time",
+            "Agree",
+        ],
+        &rows,
+    );
+
+    agreed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_input() {
+        let without_input = ExternalCommand::default();
+        let with_input: ExternalCommand = "sh solve.sh {day} {input}".parse().unwrap();
+        let other_input = InputSource::from_flag("other/");
+
+        assert!(check_input(&without_input, &other_input).is_err());
+        assert!(check_input(&with_input, &other_input).is_ok());
+        assert!(check_input(&with_input, &InputSource::Default).is_ok());
+    }
+
+    #[test]
+    fn test_parse_output() {
+        let output = "Nushell Day 1 results:
+  Part1: 11 in 0.52 ms
+  Part2:  in 1sec";
+
+        assert_eq!(
+            parse_output(output),
+            [
+                Some(ExternalPart {
+                    answer: Answer::Number(11),
+                    time: Some(Duration::from_micros(520)),
+                }),
+                Some(ExternalPart {
+                    answer: Answer::Unsolved,
+                    time: Some(Duration::from_secs(1)),
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_rust_output() {
+        let output = "Rust Day 4 results:
+  Parsed in 0ms
+  Part 1: Not completed yet";
+
+        assert_eq!(
+            parse_output(output),
+            [
+                Some(ExternalPart {
+                    answer: Answer::Text("Not completed yet".into()),
+                    time: None,
+                }),
+                None
+            ]
+        );
+    }
+}
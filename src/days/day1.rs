@@ -1,27 +1,38 @@
 use std::collections::HashMap;
 
-use crate::AdventOfCodeDay;
+use crate::{error::parse_token, AdventOfCodeDay, Answer, AocError};
 
 pub struct Day;
 
 impl AdventOfCodeDay for Day {
-    fn day(&self) -> u8 {
-        1
-    }
+    type Input<'a> = (Vec<i64>, Vec<i64>);
+
+    const DAY: u8 = 1;
 
-    fn part1(&self, input: &str) -> String {
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
         let mut nums1: Vec<i64> = Vec::new();
         let mut nums2: Vec<i64> = Vec::new();
 
-        for line in input.lines() {
+        for (line_index, line) in input.lines().enumerate() {
             let parts = line.split_whitespace().collect::<Vec<_>>();
             let [num1, num2] = parts.as_slice() else {
-                unreachable!("input was invalid format");
+                return Err(AocError::parse(
+                    line_index,
+                    0,
+                    format!("expected 2 numbers but found {}", parts.len()),
+                ));
             };
-            nums1.push(num1.parse().unwrap());
-            nums2.push(num2.parse().unwrap());
+            nums1.push(parse_token(line_index, line, num1)?);
+            nums2.push(parse_token(line_index, line, num2)?);
         }
 
+        Ok((nums1, nums2))
+    }
+
+    fn part1(&self, (nums1, nums2): &Self::Input<'_>) -> Result<Answer, AocError> {
+        let mut nums1 = nums1.clone();
+        let mut nums2 = nums2.clone();
+
         nums1.sort();
         nums2.sort();
 
@@ -31,31 +42,22 @@ impl AdventOfCodeDay for Day {
             .map(|(num1, num2)| (num1 - num2).abs())
             .sum();
 
-        result.to_string()
+        Ok(result.into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        let mut nums1: Vec<i64> = Vec::new();
-        let mut nums2: HashMap<i64, i64> = HashMap::new();
-
-        for line in input.lines() {
-            let parts = line.split_whitespace().collect::<Vec<_>>();
-            let [num1, num2] = parts.as_slice() else {
-                unreachable!("input was invalid format");
-            };
-            let num1 = num1.parse().unwrap();
-            let num2 = num2.parse().unwrap();
-            nums1.push(num1);
+    fn part2(&self, (nums1, nums2): &Self::Input<'_>) -> Result<Answer, AocError> {
+        let mut counts: HashMap<i64, i64> = HashMap::new();
 
-            let count = nums2.entry(num2).or_insert(0);
+        for num2 in nums2 {
+            let count = counts.entry(*num2).or_insert(0);
             *count += 1;
         }
 
-        let total = nums1.into_iter().fold(0, |current, num| {
-            let amount = nums2.get(&num).unwrap_or(&0);
+        let total = nums1.iter().fold(0, |current, num| {
+            let amount = counts.get(num).unwrap_or(&0);
             current + (num * amount)
         });
 
-        total.to_string()
+        Ok(total.into())
     }
 }
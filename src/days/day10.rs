@@ -15,29 +15,31 @@
 //!
 //! Then for part 2 I simply just found the number of paths inside the iterator to get each path.
 
-use std::{collections::HashSet, num::ParseIntError, str::FromStr};
+use std::{collections::HashSet, str::FromStr};
 
-use crate::AdventOfCodeDay;
+use crate::{AdventOfCodeDay, Answer, AocError};
 
 pub struct Day;
 
 impl AdventOfCodeDay for Day {
-    fn day(&self) -> u8 {
-        10
+    type Input<'a> = Map;
+
+    const DAY: u8 = 10;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
+        Map::from_str(input)
     }
 
-    fn part1(&self, input: &str) -> String {
-        let map = Map::from_str(input).unwrap();
-        map.find_trailhead_positions(true).to_string()
+    fn part1(&self, map: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(map.find_trailhead_positions(true).into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        let map = Map::from_str(input).unwrap();
-        map.find_trailhead_positions(false).to_string()
+    fn part2(&self, map: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(map.find_trailhead_positions(false).into())
     }
 }
 
-struct Map {
+pub struct Map {
     rows: Vec<Vec<u8>>,
 }
 
@@ -99,15 +101,17 @@ impl Map {
 }
 
 impl FromStr for Map {
-    type Err = ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut map = Vec::new();
 
-        for row in s.lines() {
+        for (line_index, row) in s.lines().enumerate() {
             let mut map_row = Vec::new();
-            for col in row.chars() {
-                let step = col.to_string().parse::<u8>()?;
+            for (column_index, col) in row.chars().enumerate() {
+                let step = col.to_digit(10).ok_or_else(|| {
+                    AocError::parse(line_index, column_index, format!("'{col}' is not a height"))
+                })? as u8;
                 map_row.push(step);
             }
             map.push(map_row);
@@ -116,39 +120,3 @@ impl FromStr for Map {
         Ok(Self { rows: map })
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part1() {
-        const INPUT: &str = "89010123
-78121874
-87430965
-96549874
-45678903
-32019012
-01329801
-10456732";
-
-        let result = Day.part1(INPUT);
-
-        assert_eq!(result, "36")
-    }
-    #[test]
-    fn test_part2() {
-        const INPUT: &str = "89010123
-78121874
-87430965
-96549874
-45678903
-32019012
-01329801
-10456732";
-
-        let result = Day.part2(INPUT);
-
-        assert_eq!(result, "81")
-    }
-}
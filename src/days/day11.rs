@@ -16,41 +16,46 @@
 //!
 //! This fix let me solve part 2 in 145 ms and part 1 in 45 ms.
 
-use std::{cell::RefCell, collections::HashMap, num::ParseIntError, str::FromStr};
+use std::{cell::RefCell, collections::HashMap, str::FromStr};
 
 use derive_more::derive::Display;
 
-use crate::AdventOfCodeDay;
+use crate::{error::parse_token, AdventOfCodeDay, Answer, AocError};
 
 pub struct Day;
 
 impl AdventOfCodeDay for Day {
-    fn day(&self) -> u8 {
-        11
+    type Input<'a> = Stones;
+
+    const DAY: u8 = 11;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
+        Stones::from_str(input)
     }
 
-    fn part1(&self, input: &str) -> String {
-        let stones = Stones::from_str(input).unwrap();
+    fn part1(&self, stones: &Self::Input<'_>) -> Result<Answer, AocError> {
         let sum = stones.get_number_of_stones(25);
-        sum.to_string()
+        Ok(sum.into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        let stones = Stones::from_str(input).unwrap();
+    fn part2(&self, stones: &Self::Input<'_>) -> Result<Answer, AocError> {
         let sum = stones.get_number_of_stones(75);
-        sum.to_string()
+        Ok(sum.into())
     }
 }
 
 #[derive(Display)]
 #[display("{}", stones.iter().map(|x| x.to_string()).collect::<Vec<_>>().join(" "))]
-struct Stones {
+pub struct Stones {
     stones: Vec<usize>,
     cache: RefCell<HashMap<(usize, usize), usize>>,
 }
 
 impl Stones {
     pub fn get_number_of_stones(&self, times: usize) -> usize {
+        // Start from an empty cache so each part's timing doesn't depend on what ran before it
+        self.cache.borrow_mut().clear();
+
         let stones = &self.stones;
         let sum: usize = stones
             .iter()
@@ -71,7 +76,7 @@ impl Stones {
 
             let digits = ((num as f64).log10().floor() as usize) + 1;
 
-            if digits % 2 == 0 {
+            if digits.is_multiple_of(2) {
                 let ten_pow = 10_usize.pow((digits as u32) / 2);
 
                 let num1 = num / ten_pow;
@@ -93,12 +98,12 @@ impl Stones {
 }
 
 impl FromStr for Stones {
-    type Err = ParseIntError;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let stones = s
             .split_whitespace()
-            .map(|x| x.parse::<usize>())
+            .map(|x| parse_token(0, s, x))
             .collect::<Result<Vec<_>, _>>()?;
 
         Ok(Stones {
@@ -107,16 +112,3 @@ impl FromStr for Stones {
         })
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part1() {
-        const INPUT: &str = "125 17";
-
-        let result = Day.part1(INPUT);
-        assert_eq!(result, "55312");
-    }
-}
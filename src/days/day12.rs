@@ -1,41 +1,35 @@
-use std::{
-    collections::{HashMap, HashSet},
-    str::FromStr,
-};
+use std::str::FromStr;
 
-use crate::AdventOfCodeDay;
+pub mod render;
+pub mod report;
+
+use crate::{AdventOfCodeDay, Answer, AocError};
 
 pub struct Day;
 
 impl AdventOfCodeDay for Day {
-    fn day(&self) -> u8 {
-        12
+    type Input<'a> = Garden;
+
+    const DAY: u8 = 12;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
+        Garden::from_str(input)
     }
 
-    fn part1(&self, input: &str) -> String {
-        let garden = Garden::from_str(input).unwrap();
-        let sum: usize = garden
-            .plots()
-            .iter()
-            .map(|plot| plot.area() * plot.perimeter())
-            .sum();
+    fn part1(&self, garden: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let sum: usize = garden.plots().iter().map(Plot::price).sum();
 
-        sum.to_string()
+        Ok(sum.into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        let garden = Garden::from_str(input).unwrap();
-        let sum: usize = garden
-            .plots()
-            .iter()
-            .map(|plot| plot.area() * plot.advanced_perimeter())
-            .sum();
+    fn part2(&self, garden: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let sum: usize = garden.plots().iter().map(Plot::discounted_price).sum();
 
-        sum.to_string()
+        Ok(sum.into())
     }
 }
 
-struct Garden {
+pub struct Garden {
     squares: Vec<Vec<char>>,
 }
 
@@ -48,119 +42,197 @@ impl Garden {
         row.get(col as usize).copied()
     }
 
-    pub fn plots(&self) -> Vec<Plot> {
-        let mut plots = Vec::<Plot>::new();
+    /// Gives every square the id of the region it's in, ids count up from 0 in the order regions
+    /// are first seen reading left to right, top to bottom
+    ///
+    /// Each region is flood filled from its first square using a stack rather than recursion, so
+    /// a huge region can't overflow the call stack, and as every square is only labelled once
+    /// this is linear in the size of the garden.
+    pub fn regions(&self) -> Regions {
+        let mut ids: Vec<Vec<Option<usize>>> = self
+            .squares
+            .iter()
+            .map(|row| vec![None; row.len()])
+            .collect();
+        let mut crops = Vec::new();
+        let mut stack = Vec::new();
+
         for (row, cols) in self.squares.iter().enumerate() {
-            for (col, c) in cols.iter().enumerate() {
-                if plots
-                    .iter()
-                    .any(|plot| plot.contains(row as isize, col as isize))
-                {
+            for (col, crop) in cols.iter().enumerate() {
+                if ids[row][col].is_some() {
                     continue;
                 }
-                let plot = self.plot(*c, row as isize, col as isize);
-                plots.push(plot);
+
+                let id = crops.len();
+                crops.push(*crop);
+                ids[row][col] = Some(id);
+                stack.push((row as isize, col as isize));
+
+                while let Some((row, col)) = stack.pop() {
+                    for (next_row, next_col) in [
+                        (row - 1, col), // Up
+                        (row, col + 1), // Right
+                        (row + 1, col), // Down
+                        (row, col - 1), // Left
+                    ] {
+                        if self.get(next_row, next_col) != Some(*crop) {
+                            continue;
+                        }
+                        let next = &mut ids[next_row as usize][next_col as usize];
+                        if next.is_none() {
+                            *next = Some(id);
+                            stack.push((next_row, next_col));
+                        }
+                    }
+                }
             }
         }
-        plots
-    }
-
-    fn plot(&self, c: char, row: isize, col: isize) -> Plot {
-        let set = self.find(HashSet::new(), c, row, col);
 
-        Plot {
-            grow: c,
-            squares: set,
+        Regions {
+            // Every square was reached by the sweep so all of them have an id
+            ids: ids
+                .into_iter()
+                .map(|row| row.into_iter().flatten().collect())
+                .collect(),
+            crops,
         }
     }
 
-    fn find(
-        &self,
-        mut set: HashSet<(isize, isize)>,
-        c: char,
-        row: isize,
-        col: isize,
-    ) -> HashSet<(isize, isize)> {
-        let Some(current_char) = self.get(row, col) else {
-            return set;
-        };
+    /// Measures every region, see `Regions::plots`
+    pub fn plots(&self) -> Vec<Plot> {
+        self.regions().plots()
+    }
+}
+
+/// The region id of every square in a `Garden`
+pub struct Regions {
+    ids: Vec<Vec<usize>>,
+    /// Crop grown in each region, indexed by id
+    crops: Vec<char>,
+}
 
-        if set.contains(&(row, col)) {
-            return set;
+impl Regions {
+    pub fn get(&self, row: isize, col: isize) -> Option<usize> {
+        if row < 0 || col < 0 {
+            return None;
         }
+        let row = self.ids.get(row as usize)?;
+        row.get(col as usize).copied()
+    }
 
-        if current_char != c {
-            return set;
-        }
+    pub fn count(&self) -> usize {
+        self.crops.len()
+    }
 
-        if !set.insert((row, col)) {
-            return set;
+    /// Measures every region in a single pass over the labelled squares, the plots are indexed by
+    /// region id
+    pub fn plots(&self) -> Vec<Plot> {
+        let mut plots: Vec<Plot> = self
+            .crops
+            .iter()
+            .map(|crop| Plot {
+                grow: *crop,
+                bounds: BoundingBox {
+                    top: usize::MAX,
+                    left: usize::MAX,
+                    bottom: 0,
+                    right: 0,
+                },
+                area: 0,
+                perimeter: 0,
+                sides: 0,
+            })
+            .collect();
+
+        for (row, ids) in self.ids.iter().enumerate() {
+            for (col, id) in ids.iter().enumerate() {
+                let plot = &mut plots[*id];
+                plot.bounds.top = plot.bounds.top.min(row);
+                plot.bounds.left = plot.bounds.left.min(col);
+                plot.bounds.bottom = plot.bounds.bottom.max(row);
+                plot.bounds.right = plot.bounds.right.max(col);
+
+                let (row, col) = (row as isize, col as isize);
+                let same = |row, col| self.get(row, col) == Some(*id);
+
+                plot.area += 1;
+                plot.perimeter += !same(row - 1, col) as usize; // Up
+                plot.perimeter += !same(row, col + 1) as usize; // Right
+                plot.perimeter += !same(row + 1, col) as usize; // Down
+                plot.perimeter += !same(row, col - 1) as usize; // Left
+
+                // A polygon has as many sides as it has corners, so rather than following the
+                // fence each square counts its corners. Looking at each diagonal, it's an outside
+                // corner if neither neighbour next to that diagonal is in the plot, or an inside
+                // corner if both are but the diagonal square isn't.
+                for (row_offset, col_offset) in [(-1, -1), (-1, 1), (1, 1), (1, -1)] {
+                    let vertical = same(row + row_offset, col);
+                    let horizontal = same(row, col + col_offset);
+                    let diagonal = same(row + row_offset, col + col_offset);
+
+                    let corner =
+                        (!vertical && !horizontal) || (vertical && horizontal && !diagonal);
+                    plot.sides += corner as usize;
+                }
+            }
         }
 
-        set = self.find(set, c, row - 1, col); // Up
-        set = self.find(set, c, row, col + 1); // Right
-        set = self.find(set, c, row + 1, col); // Down
-        self.find(set, c, row, col - 1) // Left
+        plots
     }
 }
 
-struct Plot {
+pub struct Plot {
     grow: char,
-    squares: HashSet<(isize, isize)>,
+    bounds: BoundingBox,
+    area: usize,
+    perimeter: usize,
+    /// Number of straight runs of fence
+    sides: usize,
 }
 
 impl Plot {
-    pub fn contains(&self, row: isize, col: isize) -> bool {
-        self.squares.contains(&(row, col))
+    pub fn grow(&self) -> char {
+        self.grow
+    }
+
+    pub fn bounds(&self) -> BoundingBox {
+        self.bounds
     }
 
     pub fn area(&self) -> usize {
-        self.squares.len()
+        self.area
     }
 
     pub fn perimeter(&self) -> usize {
-        self.squares
-            .iter()
-            .map(|(row, col)| {
-                let mut count = 0;
-
-                count += !self.contains(*row - 1, *col) as usize; // Up
-                count += !self.contains(*row, *col + 1) as usize; // Right
-                count += !self.contains(*row + 1, *col) as usize; // Down
-                count += !self.contains(*row, *col - 1) as usize; // Left
-
-                count
-            })
-            .sum()
+        self.perimeter
     }
 
-    pub fn advanced_perimeter(&self) -> usize {
-        let checked_squares: HashMap<(isize, isize), (bool, bool, bool, bool)> = self
-            .squares
-            .iter()
-            .map(|pos| (*pos, (false, false, false, false)))
-            .collect();
-
-        for (row, col) in &self.squares {
-            // Top
-            let top_edge = !self.contains(*row - 1, *col);
-            if top_edge {
-                let mut offset = 0;
-                loop {
-                    offset += 1;
-                    if !self.contains(*row - 1, *col) {
+    pub fn sides(&self) -> usize {
+        self.sides
+    }
 
-                    };
-                }
-            }
-        }
+    /// Part 1's price of the fence
+    pub fn price(&self) -> usize {
+        self.area * self.perimeter
+    }
 
-        todo!()
+    /// Part 2's bulk discount price, paying per side instead of per unit of fence
+    pub fn discounted_price(&self) -> usize {
+        self.area * self.sides
     }
 }
 
+/// Smallest rectangle of squares holding a plot, every side is inclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BoundingBox {
+    pub top: usize,
+    pub left: usize,
+    pub bottom: usize,
+    pub right: usize,
+}
+
 impl FromStr for Garden {
-    type Err = ();
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut rows = Vec::new();
@@ -180,65 +252,36 @@ impl FromStr for Garden {
 mod tests {
     use super::*;
 
-    const INPUT1: &str = "AAAA
-BBCD
-BBCC
-EEEC";
-
-    const INPUT2: &str = "OOOOO
-OXOXO
-OOOOO
-OXOXO
-OOOOO";
-
-    const INPUT3: &str = "RRRRIICCFF
-RRRRIICCCF
-VVRRRCCFFF
-VVRCCCJFFF
-VVVVCJJCFE
-VVIVCCJJEE
-VVIIICJJEE
-MIIIIIJJEE
-MIIISIJEEE
-MMMISSJEEE";
-
-    #[test]
-    fn test_part1_input1() {
-        let result = Day.part1(INPUT1);
-
-        assert_eq!(result, "140");
-    }
-    #[test]
-    fn test_part1_input2() {
-        let result = Day.part1(INPUT2);
-
-        assert_eq!(result, "772");
-    }
-
-    #[test]
-    fn test_part1_input3() {
-        let result = Day.part1(INPUT3);
-
-        assert_eq!(result, "1930");
+    /// A `size` by `size` garden with the crop at each square picked by `crop`
+    fn generate(size: usize, crop: impl Fn(usize, usize) -> char) -> Garden {
+        Garden {
+            squares: (0..size)
+                .map(|row| (0..size).map(|col| crop(row, col)).collect())
+                .collect(),
+        }
     }
 
     #[test]
-    fn test_part2_input1() {
-        let result = Day.part2(INPUT1);
-
-        assert_eq!(result, "80");
-    }
-    #[test]
-    fn test_part2_input2() {
-        let result = Day.part2(INPUT2);
+    fn test_large_region() {
+        let plots = generate(1000, |_, _| 'A').plots();
 
-        assert_eq!(result, "436");
+        assert_eq!(plots.len(), 1);
+        assert_eq!(plots[0].area(), 1_000_000);
+        assert_eq!(plots[0].perimeter(), 4000);
+        assert_eq!(plots[0].sides(), 4);
     }
 
     #[test]
-    fn test_part2_input3() {
-        let result = Day.part2(INPUT3);
-
-        assert_eq!(result, "1206");
+    fn test_many_regions() {
+        let garden = generate(
+            1000,
+            |row, col| if (row + col) % 2 == 0 { 'A' } else { 'B' },
+        );
+        let plots = garden.plots();
+
+        assert_eq!(garden.regions().count(), 1_000_000);
+        assert!(plots
+            .iter()
+            .all(|plot| (plot.area(), plot.perimeter(), plot.sides()) == (1, 4, 4)));
     }
 }
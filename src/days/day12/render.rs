@@ -0,0 +1,264 @@
+//! Draws a `Garden` with every region filled in its own colour and the fences along the region
+//! boundaries, which makes it much easier to check the pricing than staring at letters.
+//!
+//! SVG output can also label each region with its area, perimeter and sides, PPM output is a plain
+//! image for viewers that don't handle SVG.
+
+use std::fmt::Write;
+
+use super::{Garden, Plot, Regions};
+
+/// Size of a square in pixels when rendering a PPM
+const PPM_SQUARE: usize = 8;
+
+/// Renders the garden as an SVG with a unit per square, `labels` writes each region's area,
+/// perimeter and sides on its first square
+pub fn svg(garden: &Garden, labels: bool) -> String {
+    let regions = garden.regions();
+    let plots = regions.plots();
+    let (rows, cols) = size(&regions);
+
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {cols} {rows}" width="{}" height="{}">"#,
+        cols * 20,
+        rows * 20
+    );
+    svg.push('\n');
+
+    // A path per region made up of a unit square for each of its squares
+    let mut shapes = vec![String::new(); regions.count()];
+    for (row, ids) in regions.ids.iter().enumerate() {
+        for (col, id) in ids.iter().enumerate() {
+            let _ = write!(shapes[*id], "M{col} {row}h1v1h-1z");
+        }
+    }
+    for (id, (shape, plot)) in shapes.iter().zip(&plots).enumerate() {
+        let [red, green, blue] = colour(id);
+        let _ = writeln!(
+            svg,
+            r#"<path fill="rgb({red},{green},{blue})" d="{shape}"><title>{}</title></path>"#,
+            escape(&describe(plot))
+        );
+    }
+
+    let mut fences = String::new();
+    for (row, col, side) in fences_of(&regions) {
+        let _ = match side {
+            Side::Top => write!(fences, "M{col} {row}h1"),
+            Side::Right => write!(fences, "M{} {row}v1", col + 1),
+            Side::Bottom => write!(fences, "M{col} {}h1", row + 1),
+            Side::Left => write!(fences, "M{col} {row}v1"),
+        };
+    }
+    let _ = writeln!(
+        svg,
+        r#"<path fill="none" stroke="black" stroke-width="0.1" stroke-linecap="square" d="{fences}"/>"#
+    );
+
+    if labels {
+        for (id, (row, col)) in first_squares(&regions).into_iter().enumerate() {
+            let plot = &plots[id];
+            let _ = writeln!(
+                svg,
+                r#"<text x="{}" y="{}" font-size="0.22" font-family="monospace">{} {}/{}/{}</text>"#,
+                col as f64 + 0.1,
+                row as f64 + 0.55,
+                escape(&plot.grow().to_string()),
+                plot.area(),
+                plot.perimeter(),
+                plot.sides()
+            );
+        }
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Renders the garden as a binary PPM image with `PPM_SQUARE` pixels per square
+pub fn ppm(garden: &Garden) -> Vec<u8> {
+    let regions = garden.regions();
+    let (rows, cols) = size(&regions);
+    let (width, height) = (cols * PPM_SQUARE, rows * PPM_SQUARE);
+
+    let mut pixels = vec![[255; 3]; width * height];
+    for (row, ids) in regions.ids.iter().enumerate() {
+        for (col, id) in ids.iter().enumerate() {
+            for y in 0..PPM_SQUARE {
+                for x in 0..PPM_SQUARE {
+                    pixels[(row * PPM_SQUARE + y) * width + col * PPM_SQUARE + x] = colour(*id);
+                }
+            }
+        }
+    }
+
+    for (row, col, side) in fences_of(&regions) {
+        let (top, left) = (row * PPM_SQUARE, col * PPM_SQUARE);
+        for offset in 0..PPM_SQUARE {
+            let (y, x) = match side {
+                Side::Top => (top, left + offset),
+                Side::Right => (top + offset, left + PPM_SQUARE - 1),
+                Side::Bottom => (top + PPM_SQUARE - 1, left + offset),
+                Side::Left => (top + offset, left),
+            };
+            pixels[y * width + x] = [0; 3];
+        }
+    }
+
+    let mut ppm = format!("P6\n{width} {height}\n255\n").into_bytes();
+    ppm.extend(pixels.into_iter().flatten());
+    ppm
+}
+
+/// Side of a square that has a fence along it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Side {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+/// Every side of a square that borders another region or the edge of the garden
+fn fences_of(regions: &Regions) -> Vec<(usize, usize, Side)> {
+    let mut fences = Vec::new();
+    for (row, ids) in regions.ids.iter().enumerate() {
+        for (col, id) in ids.iter().enumerate() {
+            let (r, c) = (row as isize, col as isize);
+            for (side, neighbour) in [
+                (Side::Top, (r - 1, c)),
+                (Side::Right, (r, c + 1)),
+                (Side::Bottom, (r + 1, c)),
+                (Side::Left, (r, c - 1)),
+            ] {
+                if regions.get(neighbour.0, neighbour.1) != Some(*id) {
+                    fences.push((row, col, side));
+                }
+            }
+        }
+    }
+    fences
+}
+
+/// The first square of each region reading left to right, top to bottom, which is always inside
+/// the region unlike its centre
+fn first_squares(regions: &Regions) -> Vec<(usize, usize)> {
+    let mut squares = vec![None; regions.count()];
+    for (row, ids) in regions.ids.iter().enumerate() {
+        for (col, id) in ids.iter().enumerate() {
+            squares[*id].get_or_insert((row, col));
+        }
+    }
+    squares.into_iter().flatten().collect()
+}
+
+/// Rows and columns in the garden, using the widest row if they're uneven
+fn size(regions: &Regions) -> (usize, usize) {
+    let cols = regions.ids.iter().map(Vec::len).max().unwrap_or_default();
+    (regions.ids.len(), cols)
+}
+
+/// Full description of a plot shown when hovering over it
+fn describe(plot: &Plot) -> String {
+    format!(
+        "{}: area {}, perimeter {}, sides {}, price {}, discounted price {}",
+        plot.grow(),
+        plot.area(),
+        plot.perimeter(),
+        plot.sides(),
+        plot.price(),
+        plot.discounted_price()
+    )
+}
+
+/// Escapes text for use inside of SVG elements and attributes, crops can be any character
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// A light colour for a region, stepping the hue by the golden angle so regions found one after
+/// the other look nothing alike
+fn colour(id: usize) -> [u8; 3] {
+    let hue = (id as f64 * 137.508) % 360.0;
+    let (saturation, lightness) = (0.6, 0.7);
+
+    // HSL to RGB
+    let chroma = (1.0 - (2.0 * lightness - 1.0_f64).abs()) * saturation;
+    let x = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let (red, green, blue) = match hue as u32 / 60 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let light = lightness - chroma / 2.0;
+    [red, green, blue].map(|channel| ((channel + light) * 255.0).round() as u8)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    const INPUT: &str = include_str!("../../../examples/day12/example1.txt");
+
+    #[test]
+    fn test_svg() {
+        let garden = Garden::from_str(INPUT).unwrap();
+        let svg = svg(&garden, true);
+
+        assert!(svg.starts_with("<svg "));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<title>").count(), 5);
+        assert!(svg.contains(
+            "<title>C: area 4, perimeter 10, sides 8, price 40, discounted price 32</title>"
+        ));
+        assert!(svg.contains(">A 4/10/4</text>"));
+        assert!(!super::svg(&garden, false).contains("<text"));
+    }
+
+    #[test]
+    fn test_svg_escapes_crops() {
+        let svg = svg(&Garden::from_str("<&").unwrap(), true);
+
+        assert!(svg.contains("<title>&lt;: area 1,"));
+        assert!(svg.contains(">&amp; 1/4/4</text>"));
+    }
+
+    #[test]
+    fn test_ppm() {
+        let garden = Garden::from_str(INPUT).unwrap();
+        let ppm = ppm(&garden);
+        let header = format!("P6\n{0} {0}\n255\n", 4 * PPM_SQUARE);
+
+        assert!(ppm.starts_with(header.as_bytes()));
+        assert_eq!(ppm.len(), header.len() + (4 * PPM_SQUARE).pow(2) * 3);
+    }
+
+    #[test]
+    fn test_fences() {
+        let garden = Garden::from_str(INPUT).unwrap();
+        let regions = garden.regions();
+
+        // Every unit of fence is drawn once per region it borders, so once for each perimeter
+        let perimeter: usize = regions.plots().iter().map(Plot::perimeter).sum();
+        assert_eq!(fences_of(&regions).len(), perimeter);
+        assert_eq!(
+            first_squares(&regions),
+            [(0, 0), (1, 0), (1, 2), (1, 3), (3, 0)]
+        );
+    }
+}
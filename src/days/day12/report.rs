@@ -0,0 +1,133 @@
+//! What each region's fence costs, so regions can be checked one at a time against the worked
+//! examples in the puzzle rather than only the summed price.
+
+use crate::{output::json_string, runner::aligned};
+
+use super::{Garden, Plot};
+
+/// Every plot in the garden, most expensive first
+///
+/// Plots are sorted by their part 1 price, then their part 2 price, then by where they are in the
+/// garden so the order is always the same.
+pub fn breakdown(garden: &Garden) -> Vec<Plot> {
+    let mut plots = garden.plots();
+    plots.sort_by_key(|plot| {
+        let bounds = plot.bounds();
+        (
+            std::cmp::Reverse((plot.price(), plot.discounted_price())),
+            bounds.top,
+            bounds.left,
+        )
+    });
+    plots
+}
+
+/// A table with a row per plot and the total prices at the bottom
+pub fn table(plots: &[Plot]) -> String {
+    let mut rows: Vec<Vec<String>> = plots
+        .iter()
+        .map(|plot| {
+            let bounds = plot.bounds();
+            vec![
+                plot.grow().to_string(),
+                format!(
+                    "({}, {})..=({}, {})",
+                    bounds.top, bounds.left, bounds.bottom, bounds.right
+                ),
+                plot.area().to_string(),
+                plot.perimeter().to_string(),
+                plot.sides().to_string(),
+                plot.price().to_string(),
+                plot.discounted_price().to_string(),
+            ]
+        })
+        .collect();
+
+    let total = |price: fn(&Plot) -> usize| plots.iter().map(price).sum::<usize>().to_string();
+    rows.push(vec![
+        "Total".to_string(),
+        String::new(),
+        String::new(),
+        String::new(),
+        String::new(),
+        total(Plot::price),
+        total(Plot::discounted_price),
+    ]);
+
+    aligned(
+        &[
+            "Crop",
+            "Bounds (row, col)",
+            "Area",
+            "Perimeter",
+            "Sides",
+            "Price",
+            "Discounted",
+        ],
+        &rows,
+    )
+}
+
+/// A JSON array with an object per plot, the bounds are inclusive
+pub fn json(plots: &[Plot]) -> String {
+    let objects: Vec<String> = plots
+        .iter()
+        .map(|plot| {
+            let bounds = plot.bounds();
+            format!(
+                r#"  {{"crop": {}, "top": {}, "left": {}, "bottom": {}, "right": {}, "area": {}, "perimeter": {}, "sides": {}, "price": {}, "discounted_price": {}}}"#,
+                json_string(&plot.grow().to_string()),
+                bounds.top,
+                bounds.left,
+                bounds.bottom,
+                bounds.right,
+                plot.area(),
+                plot.perimeter(),
+                plot.sides(),
+                plot.price(),
+                plot.discounted_price(),
+            )
+        })
+        .collect();
+
+    format!("[\n{}\n]", objects.join(",\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    const INPUT: &str = include_str!("../../../examples/day12/example1.txt");
+
+    #[test]
+    fn test_table() {
+        let plots = breakdown(&Garden::from_str(INPUT).unwrap());
+
+        assert_eq!(
+            table(&plots),
+            "Crop  | Bounds (row, col) | Area | Perimeter | Sides | Price | Discounted
+------|-------------------|------|-----------|-------|-------|-----------
+C     | (1, 2)..=(3, 3)   | 4    | 10        | 8     | 40    | 32
+A     | (0, 0)..=(0, 3)   | 4    | 10        | 4     | 40    | 16
+B     | (1, 0)..=(2, 1)   | 4    | 8         | 4     | 32    | 16
+E     | (3, 0)..=(3, 2)   | 3    | 8         | 4     | 24    | 12
+D     | (1, 3)..=(1, 3)   | 1    | 4         | 4     | 4     | 4
+Total |                   |      |           |       | 140   | 80"
+        );
+    }
+
+    #[test]
+    fn test_json() {
+        let plots = breakdown(&Garden::from_str("AB").unwrap());
+
+        assert_eq!(
+            json(&plots),
+            r#"[
+  {"crop": "A", "top": 0, "left": 0, "bottom": 0, "right": 0, "area": 1, "perimeter": 4, "sides": 4, "price": 4, "discounted_price": 4},
+  {"crop": "B", "top": 0, "left": 1, "bottom": 0, "right": 1, "area": 1, "perimeter": 4, "sides": 4, "price": 4, "discounted_price": 4}
+]"#
+        );
+    }
+}
@@ -1,30 +1,40 @@
-use crate::AdventOfCodeDay;
+use crate::{error::parse_token, AdventOfCodeDay, Answer, AocError};
 
 pub struct Day;
 
 impl AdventOfCodeDay for Day {
-    fn day(&self) -> u8 {
-        2
+    type Input<'a> = Vec<Vec<i64>>;
+
+    const DAY: u8 = 2;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(line_index, line)| {
+                line.split_whitespace()
+                    .map(|x| parse_token(line_index, line, x))
+                    .collect()
+            })
+            .collect()
     }
 
-    fn part1(&self, input: &str) -> String {
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
         let result = input
-            .lines()
-            .map(|x| x.split_whitespace().map(|x| x.parse::<i64>().unwrap()))
-            .filter(|iter| is_valid_part1(iter.clone()))
+            .iter()
+            .filter(|report| is_valid_part1(report.iter().copied()))
             .count();
 
-        result.to_string()
+        Ok(result.into())
     }
 
-    fn part2(&self, input: &str) -> String {
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
         let result = input
-            .lines()
-            .map(|x| x.split_whitespace().map(|x| x.parse::<i64>().unwrap()))
-            .filter(|iter| is_valid_part2(iter.clone()))
+            .iter()
+            .filter(|report| is_valid_part2(report.iter().copied()))
             .count();
 
-        result.to_string()
+        Ok(result.into())
     }
 }
 
@@ -1,79 +1,79 @@
 use regex::Regex;
 
-use crate::AdventOfCodeDay;
+use crate::{AdventOfCodeDay, Answer, AocError};
 
 pub struct Day;
 
 impl AdventOfCodeDay for Day {
-    fn day(&self) -> u8 {
-        3
-    }
-
-    fn part1(&self, input: &str) -> String {
-        let regex = Regex::new(r#"mul\((?P<num1>\d{1,3}),(?P<num2>\d{1,3})\)"#).unwrap();
-
-        let mut total = 0;
-        for capture in regex.captures_iter(input) {
-            let num1 = capture.name("num1").unwrap().as_str();
-            let num2 = capture.name("num2").unwrap().as_str();
+    type Input<'a> = Vec<Instruction>;
 
-            total += num1.parse::<u64>().unwrap() * num2.parse::<u64>().unwrap();
-        }
-
-        total.to_string()
-    }
+    const DAY: u8 = 3;
 
-    fn part2(&self, input: &str) -> String {
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
         let regex =
             Regex::new(r#"mul\((?P<num1>\d{1,3}),(?P<num2>\d{1,3})\)|(?P<do>do\(\)|don't\(\))"#)
                 .unwrap();
 
-        let mut is_ignore = false;
-        let mut total = 0;
+        let mut instructions = Vec::new();
         for capture in regex.captures_iter(input) {
             if let Some(do_val) = capture.name("do").map(|x| x.as_str()) {
                 match do_val {
                     "do()" => {
-                        is_ignore = false;
+                        instructions.push(Instruction::Do);
                         continue;
                     }
                     "don't()" => {
-                        is_ignore = true;
+                        instructions.push(Instruction::Dont);
                         continue;
                     }
                     _ => (),
                 }
             };
 
-            if is_ignore {
-                continue;
-            }
-
             let num1 = capture.name("num1").unwrap().as_str();
             let num2 = capture.name("num2").unwrap().as_str();
 
-            total += num1.parse::<u64>().unwrap() * num2.parse::<u64>().unwrap();
+            instructions.push(Instruction::Mul(
+                num1.parse::<u64>().unwrap(),
+                num2.parse::<u64>().unwrap(),
+            ));
         }
 
-        total.to_string()
+        Ok(instructions)
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let mut total = 0;
+        for instruction in input {
+            if let Instruction::Mul(num1, num2) = instruction {
+                total += num1 * num2;
+            }
+        }
 
-    #[test]
-    fn test_part1() {
-        let result =
-            Day.part1("xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))");
-        assert_eq!(result, "161".to_owned())
+        Ok(total.into())
     }
 
-    #[test]
-    fn test_part2() {
-        let result =
-            Day.part2("xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))");
-        assert_eq!(result, "48".to_owned())
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let mut is_ignore = false;
+        let mut total = 0;
+        for instruction in input {
+            match instruction {
+                Instruction::Do => is_ignore = false,
+                Instruction::Dont => is_ignore = true,
+                Instruction::Mul(num1, num2) => {
+                    if !is_ignore {
+                        total += num1 * num2;
+                    }
+                }
+            }
+        }
+
+        Ok(total.into())
     }
 }
+
+pub enum Instruction {
+    Mul(u64, u64),
+    Do,
+    Dont,
+}
@@ -15,52 +15,60 @@
 
 use std::str::{Chars, FromStr};
 
-use crate::AdventOfCodeDay;
+use crate::{AdventOfCodeDay, Answer, AocError};
 
 pub struct Day;
 
 impl AdventOfCodeDay for Day {
-    fn day(&self) -> u8 {
-        4
-    }
+    type Input<'a> = Board;
+
+    const DAY: u8 = 4;
 
-    fn part1(&self, input: &str) -> String {
-        let board = Board::from_str(input).unwrap();
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
+        Board::from_str(input)
+    }
 
+    fn part1(&self, board: &Self::Input<'_>) -> Result<Answer, AocError> {
         let mut total = 0;
         for (row, col, _) in board.iter() {
             total += board.look_for("XMAS", row, col);
         }
-        total.to_string()
+        Ok(total.into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        let board = Board::from_str(input).unwrap();
+    fn part2(&self, board: &Self::Input<'_>) -> Result<Answer, AocError> {
         let mut total = 0;
         for (row, col, _) in board.iter() {
             total += board.find_mas(row, col) as usize;
         }
-        total.to_string()
+        Ok(total.into())
     }
 }
 
 #[derive(Debug)]
-struct Board {
+pub struct Board {
     rows: Vec<Vec<char>>,
     pub width: usize,
 }
 
 impl FromStr for Board {
-    type Err = ();
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut width = 0;
         let mut rows = Vec::new();
-        for line in s.lines() {
+        for (line_index, line) in s.lines().enumerate() {
             let mut row = Vec::new();
             for c in line.chars() {
                 row.push(c);
             }
+            if line_index > 0 && row.len() != width {
+                return Err(AocError::parse(
+                    line_index,
+                    row.len().min(width),
+                    format!("expected {width} letters like the lines above"),
+                ));
+            }
             width = row.len();
             rows.push(row);
         }
@@ -158,7 +166,7 @@ impl Board {
     }
 }
 
-struct BoardIter<'a> {
+pub struct BoardIter<'a> {
     board: &'a Board,
     index: usize,
 }
@@ -177,45 +185,3 @@ impl Iterator for BoardIter<'_> {
         Some((row, col, current_char))
     }
 }
-
-#[cfg(test)]
-mod tests {
-
-    use super::*;
-
-    #[test]
-    fn test_part1() {
-        const TEST_INP: &str = "MMMSXXMASM
-MSAMXMSMSA
-AMXSXMAAMM
-MSAMASMSMX
-XMASAMXAMM
-XXAMMXXAMA
-SMSMSASXSS
-SAXAMASAAA
-MAMMMXMMMM
-MXMXAXMASX";
-
-        let result = Day.part1(TEST_INP);
-
-        assert_eq!(result, "18")
-    }
-
-    #[test]
-    fn test_part2() {
-        const TEST_INP: &str = "MMMSXXMASM
-MSAMXMSMSA
-AMXSXMAAMM
-MSAMASMSMX
-XMASAMXAMM
-XXAMMXXAMA
-SMSMSASXSS
-SAXAMASAAA
-MAMMMXMMMM
-MXMXAXMASX";
-
-        let result = Day.part2(TEST_INP);
-
-        assert_eq!(result, "9")
-    }
-}
@@ -13,45 +13,61 @@ use std::{
     ops::{Deref, DerefMut},
 };
 
-use crate::AdventOfCodeDay;
+use crate::{error::parse_token, AdventOfCodeDay, Answer, AocError};
 
 pub struct Day;
 
 impl AdventOfCodeDay for Day {
-    fn day(&self) -> u8 {
-        5
-    }
+    type Input<'a> = Manual<'a>;
+
+    const DAY: u8 = 5;
 
-    fn part1(&self, input: &str) -> String {
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
         let parts = input.split("\n\n").collect::<Vec<_>>();
         let [ordering, updates] = parts.as_slice() else {
-            panic!("Invalid input");
+            return Err(AocError::Unsupported(
+                "expected the ordering rules and updates to be separated by a blank line".into(),
+            ));
         };
 
-        let ordering: OrderingMap = (*ordering).try_into().unwrap();
+        // Updates start after the ordering rules and the blank line
+        let first_update_line = ordering.lines().count() + 1;
 
-        let updates: Vec<_> = updates
+        let ordering: OrderingMap = (*ordering).try_into()?;
+        let updates = updates
             .lines()
-            .map(|line| Updates::new(&ordering, line))
+            .enumerate()
+            .map(|(line_index, line)| {
+                let values: Vec<_> = line.split(",").collect();
+                for value in &values {
+                    // Checked here so `Updates::middle_val` can't fail later on
+                    parse_token::<u64>(first_update_line + line_index, line, value)?;
+                }
+                Ok(values)
+            })
+            .collect::<Result<_, AocError>>()?;
+
+        Ok(Manual { ordering, updates })
+    }
+
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let updates: Vec<_> = input
+            .updates
+            .iter()
+            .map(|values| Updates::new(&input.ordering, values))
             .filter(|x| x.is_sorted())
             .collect();
 
         let middle_sum: u64 = updates.iter().map(Updates::middle_val).sum();
 
-        middle_sum.to_string()
+        Ok(middle_sum.into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        let parts = input.split("\n\n").collect::<Vec<_>>();
-        let [ordering, updates] = parts.as_slice() else {
-            panic!("Invalid input");
-        };
-
-        let ordering: OrderingMap = (*ordering).try_into().unwrap();
-
-        let updates: Vec<_> = updates
-            .lines()
-            .map(|line| Updates::new(&ordering, line))
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let updates: Vec<_> = input
+            .updates
+            .iter()
+            .map(|values| Updates::new(&input.ordering, values))
             .filter(|x| !x.is_sorted())
             .map(|mut updates| {
                 updates.sort();
@@ -61,17 +77,23 @@ impl AdventOfCodeDay for Day {
 
         let middle_sum: u64 = updates.iter().map(Updates::middle_val).sum();
 
-        middle_sum.to_string()
+        Ok(middle_sum.into())
     }
 }
 
+/// The parsed page ordering rules along with each list of updates
+pub struct Manual<'a> {
+    ordering: OrderingMap<'a>,
+    updates: Vec<Vec<&'a str>>,
+}
+
 /// Updates stores a list of Entries that can be sorted
 struct Updates<'a>(Vec<Entry<'a>>);
 
 impl<'a> Updates<'a> {
-    pub fn new(ordering: &'a OrderingMap, line: &'a str) -> Self {
-        let x = line
-            .split(",")
+    pub fn new(ordering: &'a OrderingMap, values: &[&'a str]) -> Self {
+        let x = values
+            .iter()
             .map(|val| Entry::new(ordering, val))
             .collect::<Vec<_>>();
         Self(x)
@@ -144,21 +166,25 @@ impl<'a> From<&Entry<'a>> for &'a str {
 }
 
 #[derive(Debug)]
-struct OrderingMap<'a>(HashMap<&'a str, HashSet<&'a str>>);
+pub struct OrderingMap<'a>(HashMap<&'a str, HashSet<&'a str>>);
 
 /// Had to use `TryFrom` instead of `FromStr` because `FromStr` doesn't retain
 /// lifetime data of the given string input
 impl<'a> TryFrom<&'a str> for OrderingMap<'a> {
-    type Error = String;
+    type Error = AocError;
 
     fn try_from(value: &'a str) -> Result<Self, Self::Error> {
         // Parse the ordering into a map
         let mut ordering_map: HashMap<&str, HashSet<&str>> = HashMap::new();
-        for line in value.lines() {
+        for (line_index, line) in value.lines().enumerate() {
             let parts = line.split("|").collect::<Vec<_>>();
 
             let [num1, num2] = parts.as_slice() else {
-                return Err(format!("Invalid ordering {line}"));
+                return Err(AocError::parse(
+                    line_index,
+                    0,
+                    format!("invalid ordering '{line}'"),
+                ));
             };
 
             let values = ordering_map.entry(num1).or_default();
@@ -178,80 +204,3 @@ impl OrderingMap<'_> {
         values.contains(num2)
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part1() {
-        const INPUT: &str = "47|53
-97|13
-97|61
-97|47
-75|29
-61|13
-75|53
-29|13
-97|29
-53|29
-61|53
-97|53
-61|29
-47|13
-75|47
-97|75
-47|61
-75|61
-47|29
-75|13
-53|13
-
-75,47,61,53,29
-97,61,53,29,13
-75,29,13
-75,97,47,61,53
-61,13,29
-97,13,75,29,47";
-
-        let result = Day.part1(INPUT);
-
-        assert_eq!(result, "143");
-    }
-
-    #[test]
-    fn test_part2() {
-        const INPUT: &str = "47|53
-97|13
-97|61
-97|47
-75|29
-61|13
-75|53
-29|13
-97|29
-53|29
-61|53
-97|53
-61|29
-47|13
-75|47
-97|75
-47|61
-75|61
-47|29
-75|13
-53|13
-
-75,47,61,53,29
-97,61,53,29,13
-75,29,13
-75,97,47,61,53
-61,13,29
-97,13,75,29,47";
-
-        let result = Day.part2(INPUT);
-
-        assert_eq!(result, "123");
-    }
-}
@@ -27,29 +27,31 @@ use std::{
     thread,
 };
 
-use crate::AdventOfCodeDay;
+use crate::{AdventOfCodeDay, Answer, AocError};
 
 pub struct Day;
 
 impl AdventOfCodeDay for Day {
-    fn day(&self) -> u8 {
-        6
+    type Input<'a> = Layout;
+
+    const DAY: u8 = 6;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
+        Layout::from_str(input)
     }
 
-    fn part1(&self, input: &str) -> String {
-        let mut layout = Layout::from_str(input).unwrap();
+    fn part1(&self, layout: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let mut layout = layout.clone();
 
         // Step through
         while layout.step() {}
 
-        layout.visited_squares().to_string()
+        Ok(layout.visited_squares().into())
     }
 
-    fn part2(&self, input: &str) -> String {
+    fn part2(&self, layout: &Self::Input<'_>) -> Result<Answer, AocError> {
         const THREADS: usize = 100;
 
-        let layout = Layout::from_str(input).unwrap();
-
         let mut threads = Vec::with_capacity(THREADS);
 
         let mut success = 0;
@@ -72,7 +74,7 @@ impl AdventOfCodeDay for Day {
         }
         threads.clear();
 
-        success.to_string()
+        Ok(success.into())
     }
 }
 
@@ -96,7 +98,7 @@ fn is_finite(mut layout: Layout, (row, col): (isize, isize)) -> bool {
 }
 
 #[derive(Debug, Clone)]
-struct Layout {
+pub struct Layout {
     pub obstacles: HashSet<(isize, isize)>,
     character: (isize, isize),
     character_direction: Direction,
@@ -107,7 +109,7 @@ struct Layout {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
-enum Direction {
+pub enum Direction {
     Up,
     Right,
     Down,
@@ -243,7 +245,7 @@ impl Display for Layout {
 }
 
 impl FromStr for Layout {
-    type Err = String;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut character = None;
@@ -270,7 +272,8 @@ impl FromStr for Layout {
             width = inner_width;
         }
 
-        let character = character.ok_or("No character found in input".to_string())?;
+        let character =
+            character.ok_or(AocError::Unsupported("no guard '^' found in input".into()))?;
 
         let visited = HashSet::new();
         // visited.insert((character.0, character.1, Direction::Up));
@@ -286,44 +289,3 @@ impl FromStr for Layout {
         })
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part1() {
-        const INPUT: &str = "....#.....
-.........#
-..........
-..#.......
-.......#..
-..........
-.#..^.....
-........#.
-#.........
-......#...";
-
-        let result = Day.part1(INPUT);
-
-        assert_eq!(result, "41");
-    }
-
-    #[test]
-    fn test_part2() {
-        const INPUT: &str = "....#.....
-.........#
-..........
-..#.......
-.......#..
-..........
-.#..^.....
-........#.
-#.........
-......#...";
-
-        let result = Day.part2(INPUT);
-
-        assert_eq!(result, "6");
-    }
-}
@@ -20,7 +20,7 @@
 //!
 //! For instance my first thought to store an Equation was to have something like this:
 //!
-//! ```rust
+//! ```rust,ignore
 //! struct Equation {
 //!     nums: Vec<isize>,
 //!     operators: Vec<Operator>,
@@ -34,7 +34,7 @@
 //! undefinded behaviour.
 //!
 //! Instead I went with
-//! ```rust
+//! ```rust,ignore
 //! struct Equation {
 //!     first_num: Option<isize>,
 //!     other_nums: Vec<(Operator, isize)>
@@ -58,29 +58,38 @@ use std::str::FromStr;
 use derive_more::derive::Display;
 use strum::{EnumIter, IntoEnumIterator};
 
-use crate::AdventOfCodeDay;
+use crate::{error::parse_token, AdventOfCodeDay, Answer, AocError};
 
 pub struct Day;
 
 impl AdventOfCodeDay for Day {
-    fn day(&self) -> u8 {
-        7
+    type Input<'a> = Vec<InputLine>;
+
+    const DAY: u8 = 7;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(line_index, line)| {
+                InputLine::from_str(line).map_err(|err| err.on_line(line_index))
+            })
+            .collect()
     }
 
-    fn part1(&self, input: &str) -> String {
-        run_day(input, false)
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(run_day(input, false))
     }
 
-    fn part2(&self, input: &str) -> String {
-        run_day(input, true)
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(run_day(input, true))
     }
 }
 
-fn run_day(input: &str, part2: bool) -> String {
+fn run_day(input: &[InputLine], part2: bool) -> Answer {
     let mut res = 0;
 
-    for line in input.lines() {
-        let input = InputLine::from_str(line).unwrap();
+    for input in input {
         let expected = input.expected_result;
 
         let eqs = input.get_equations(part2);
@@ -95,40 +104,40 @@ fn run_day(input: &str, part2: bool) -> String {
             }
         }
     }
-    res.to_string()
+    res.into()
 }
 
-struct InputLine {
+pub struct InputLine {
     expected_result: isize,
     numbers: Vec<isize>,
 }
 
 impl FromStr for InputLine {
-    type Err = String;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let parts = s.split(": ").collect::<Vec<_>>();
 
         let [result, nums] = parts.as_slice() else {
-            return Err(format!("Failed parsing line '{s}'"));
+            return Err(AocError::parse(0, 0, format!("failed parsing line '{s}'")));
         };
 
         let nums: Vec<_> = nums
             .split_whitespace()
-            .map(|x| x.parse().unwrap())
-            .collect();
+            .map(|x| parse_token(0, s, x))
+            .collect::<Result<_, _>>()?;
 
         Ok(Self {
-            expected_result: result.parse().unwrap(),
+            expected_result: parse_token(0, s, result)?,
             numbers: nums,
         })
     }
 }
 
 impl InputLine {
-    pub fn get_equations(self, part2: bool) -> Vec<Equation> {
+    pub fn get_equations(&self, part2: bool) -> Vec<Equation> {
         let mut eqs = vec![Equation::default()];
-        for num in self.numbers {
+        for num in self.numbers.iter().copied() {
             let mut new_eqs = Vec::new();
             for eq in &eqs {
                 new_eqs.append(&mut eq.add_num(part2, num));
@@ -148,7 +157,7 @@ impl InputLine {
     },
     other_nums.iter().map(|(op, num)| format!("{op} {num}")).collect::<Vec<_>>().join(" "))
 ]
-struct Equation {
+pub struct Equation {
     first_num: Option<isize>,
     other_nums: Vec<(Operator, isize)>,
 }
@@ -197,41 +206,3 @@ enum Operator {
     #[display("||")]
     Concat,
 }
-
-#[cfg(test)]
-mod tests {
-
-    use super::*;
-
-    #[test]
-    fn test_part1() {
-        const INPUT: &str = "190: 10 19
-3267: 81 40 27
-83: 17 5
-156: 15 6
-7290: 6 8 6 15
-161011: 16 10 13
-192: 17 8 14
-21037: 9 7 18 13
-292: 11 6 16 20";
-
-        let results = Day.part1(INPUT);
-        assert_eq!(results, "3749");
-    }
-
-    #[test]
-    fn test_part2() {
-        const INPUT: &str = "190: 10 19
-3267: 81 40 27
-83: 17 5
-156: 15 6
-7290: 6 8 6 15
-161011: 16 10 13
-192: 17 8 14
-21037: 9 7 18 13
-292: 11 6 16 20";
-
-        let results = Day.part2(INPUT);
-        assert_eq!(results, "11387");
-    }
-}
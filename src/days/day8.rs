@@ -4,26 +4,30 @@ use std::{
     str::FromStr,
 };
 
-use crate::AdventOfCodeDay;
+use crate::{AdventOfCodeDay, Answer, AocError};
 
 pub struct Day;
 
 impl AdventOfCodeDay for Day {
-    fn day(&self) -> u8 {
-        8
+    type Input<'a> = Map;
+
+    const DAY: u8 = 8;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
+        Map::from_str(input)
     }
 
-    fn part1(&self, input: &str) -> String {
-        run_day(input, false)
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(run_day(input, false))
     }
 
-    fn part2(&self, input: &str) -> String {
-        run_day(input, true)
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(run_day(input, true))
     }
 }
 
-fn run_day(input: &str, part2: bool) -> String {
-    let mut map = Map::from_str(input).unwrap();
+fn run_day(map: &Map, part2: bool) -> Answer {
+    let mut map = map.clone();
 
     let antennas = map.get_all_antennas();
     for antenna in antennas {
@@ -42,14 +46,13 @@ fn run_day(input: &str, part2: bool) -> String {
         }
     }
 
-    println!("{map}");
     let result = map.count_freq();
 
-    result.to_string()
+    result.into()
 }
 
-#[derive(Debug)]
-struct Map {
+#[derive(Debug, Clone)]
+pub struct Map {
     places: HashMap<(isize, isize), char>,
     freqs: HashSet<(isize, isize)>,
     width: isize,
@@ -102,148 +105,51 @@ impl Map {
         row < 0 || row >= self.height || col < 0 || col >= self.width
     }
 
-    fn get_freqs_left(
+    /// Antinodes of a pair of antennas with the same frequency, some of which can be off the map
+    ///
+    /// Without `recurse` there's one antinode on either side of the pair, as far from the nearest
+    /// antenna as the antennas are from each other. With it, every position in line with the pair
+    /// at that spacing is an antinode until the edge of the map, including the antennas
+    /// themselves.
+    ///
+    /// The offsets between the antennas keep their signs so the order of the pair doesn't matter
+    /// and anti-diagonal pairs aren't mirrored.
+    pub fn get_frequencies(
         &self,
-        (pos1_row, pos1_col): (isize, isize),
-        (pos2_row, pos2_col): (isize, isize),
+        pos1: (isize, isize),
+        pos2: (isize, isize),
         recurse: bool,
     ) -> HashSet<(isize, isize)> {
-        let row_offset = (pos2_row - pos1_row).abs();
-        let col_offset = (pos2_col - pos1_col).abs();
-
-        let mut results = HashSet::new();
-        let val = (pos1_row - row_offset, pos1_col - col_offset);
-
-        if recurse && !self.out_of_bounds(val) {
-            results = results
-                .union(&self.get_freqs_left(val, (pos1_row, pos1_col), recurse))
-                .copied()
-                .collect();
+        if !recurse {
+            return HashSet::from([
+                self.get_freq_left(pos1, pos2),
+                self.get_freq_right(pos1, pos2),
+            ]);
         }
 
-        results.insert(val);
-        results
-    }
+        let mut points = HashSet::from([pos1, pos2]);
 
-    fn get_freqs_right(
-        &self,
-        (pos1_row, pos1_col): (isize, isize),
-        (pos2_row, pos2_col): (isize, isize),
-        recurse: bool,
-    ) -> HashSet<(isize, isize)> {
-        let row_offset = (pos2_row - pos1_row).abs();
-        let col_offset = (pos2_col - pos1_col).abs();
-
-        let mut results = HashSet::new();
-        let val = (pos2_row + row_offset, pos2_col + col_offset);
-
-        if recurse && !self.out_of_bounds(val) {
-            results = results
-                .union(&self.get_freqs_right((pos2_row, pos2_col), val, recurse))
-                .copied()
-                .collect();
+        let (mut left, mut right) = (pos1, pos2);
+        loop {
+            let next = self.get_freq_left(left, right);
+            if self.out_of_bounds(next) {
+                break;
+            }
+            points.insert(next);
+            (left, right) = (next, left);
         }
 
-        results.insert(val);
-        results
-    }
-
-    pub fn get_frequencies(
-        &self,
-        og_pos1: (isize, isize),
-        og_pos2: (isize, isize),
-        recurse: bool,
-    ) -> HashSet<(isize, isize)> {
-        let mut result = HashSet::new();
-
-        // println!("{og_pos1:?} {og_pos2:?}");
-
-
-        // TODO trying to get this working, it sometimes works sometimes doesn't I assume this is
-        // because of the order
-        let pos1 = if og_pos1.0 > og_pos2.0 {
-            og_pos1
-        } else {
-            og_pos2
-        };
-        let pos2 = if og_pos1.0 > og_pos2.0 {
-            og_pos2
-        } else {
-            og_pos1
-        };
-
-        // println!("{pos1:?} {pos2:?}");
-
-        result = result
-            .union(&self.get_freqs_left(pos1, pos2, recurse))
-            .copied()
-            .collect();
-        result = result
-            .union(&self.get_freqs_right(pos1, pos2, recurse))
-            .copied()
-            .collect();
+        let (mut left, mut right) = (pos1, pos2);
+        loop {
+            let next = self.get_freq_right(left, right);
+            if self.out_of_bounds(next) {
+                break;
+            }
+            points.insert(next);
+            (left, right) = (right, next);
+        }
 
-        result
-
-        // let mut points = HashSet::new();
-        //
-        // let mut pos1 = original_pos1;
-        // let mut pos2 = original_pos2;
-        // loop {
-        //     let new_pos1 = self.get_freq_left(pos1, pos2);
-        //     if !recurse || self.out_of_bounds(new_pos1) {
-        //         break;
-        //     }
-        //     points.insert(new_pos1);
-        //     pos1 = pos2;
-        //     pos2 = new_pos1;
-        // }
-        //
-        // let mut pos1 = original_pos1;
-        // let mut pos2 = original_pos2;
-        // loop {
-        //     let new_pos2 = self.get_freq_right(pos1, pos2);
-        //     if !recurse || self.out_of_bounds(new_pos2) {
-        //         break;
-        //     }
-        //     points.insert(new_pos2);
-        //     pos1 = pos2;
-        //     pos2 = new_pos2;
-        // }
-        //
-        // points
-        //
-        //
-        // let row_offset = pos2_row - pos1_row;
-        // let col_offset = pos2_col - pos1_col;
-        //
-        // let pos1 = (pos1_row - row_offset, pos1_col - col_offset);
-        // let pos2 = (pos2_row + row_offset, pos2_col + col_offset);
-        //
-        // let mut positions = HashSet::new();
-        //
-        // if !self.out_of_bounds(pos1) && !self.places.contains_key(&pos1) {
-        //     positions.insert(pos1);
-        //     if recurse {
-        //         positions = positions
-        //             .union(&self.get_frequencies(pos1, (pos1_row, pos1_col), recurse))
-        //             .copied()
-        //             .collect();
-        //     }
-        // }
-        // println!("Checking: {pos2:?} {:?}", (pos2_row, pos2_col));
-        // if !self.out_of_bounds(pos2) && !self.places.contains_key(&pos2) {
-        //     println!("Worked!");
-        //     positions.insert(pos2);
-        //     if recurse {
-        //         positions = positions
-        //             .union(&self.get_frequencies(pos2, (pos2_row, pos2_col), recurse))
-        //             .copied()
-        //             .collect();
-        //     }
-        // }
-        //
-        // positions
+        points
     }
 
     fn get_freq_left(
@@ -256,6 +162,7 @@ impl Map {
 
         (pos1_row - row_offset, pos1_col - col_offset)
     }
+
     fn get_freq_right(
         &self,
         (pos1_row, pos1_col): (isize, isize),
@@ -269,7 +176,7 @@ impl Map {
 }
 
 impl FromStr for Map {
-    type Err = String;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut places = HashMap::new();
@@ -321,45 +228,46 @@ impl Display for Map {
 
 #[cfg(test)]
 mod tests {
-
     use super::*;
 
-    //     #[test]
-    //     fn test_part1() {
-    //         const INPUT: &str = "............
-    // ........0...
-    // .....0......
-    // .......0....
-    // ....0.......
-    // ......A.....
-    // ............
-    // ............
-    // ........A...
-    // .........A..
-    // ............
-    // ............";
-    //
-    //         let results = Day.part1(INPUT);
-    //         assert_eq!(results, "14");
-    //     }
+    fn empty_map() -> Map {
+        Map::from_str(&"..........\n".repeat(10)).unwrap()
+    }
+
+    /// Part 1's antinodes of a pair given in both orders, which should give the same result
+    fn antinodes(pos1: (isize, isize), pos2: (isize, isize)) -> HashSet<(isize, isize)> {
+        let map = empty_map();
+        let antinodes = map.get_frequencies(pos1, pos2, false);
+        assert_eq!(map.get_frequencies(pos2, pos1, false), antinodes);
+        antinodes
+    }
+
+    #[test]
+    fn test_horizontal_pair() {
+        assert_eq!(antinodes((4, 3), (4, 5)), HashSet::from([(4, 1), (4, 7)]));
+    }
+
+    #[test]
+    fn test_vertical_pair() {
+        assert_eq!(antinodes((3, 4), (5, 4)), HashSet::from([(1, 4), (7, 4)]));
+    }
+
+    #[test]
+    fn test_diagonal_pair() {
+        assert_eq!(antinodes((3, 4), (5, 5)), HashSet::from([(1, 3), (7, 6)]));
+    }
+
+    #[test]
+    fn test_anti_diagonal_pair() {
+        assert_eq!(antinodes((3, 5), (5, 4)), HashSet::from([(1, 6), (7, 3)]));
+    }
 
     #[test]
-    fn test_part2() {
-        const INPUT: &str = "............
-........0...
-.....0......
-.......0....
-....0.......
-......A.....
-............
-............
-........A...
-.........A..
-............
-............";
-
-        println!("About to run");
-        let results = Day.part2(INPUT);
-        assert_eq!(results, "34");
+    fn test_resonant_pair() {
+        let map = empty_map();
+        let expected = HashSet::from([(0, 8), (2, 6), (4, 4), (6, 2), (8, 0)]);
+
+        assert_eq!(map.get_frequencies((2, 6), (4, 4), true), expected);
+        assert_eq!(map.get_frequencies((4, 4), (2, 6), true), expected);
     }
 }
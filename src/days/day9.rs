@@ -24,35 +24,39 @@
 
 use std::{collections::BTreeSet, fmt::Display, str::FromStr};
 
-use crate::AdventOfCodeDay;
+use crate::{AdventOfCodeDay, Answer, AocError};
 
 pub struct Day;
 
 impl AdventOfCodeDay for Day {
-    fn day(&self) -> u8 {
-        9
+    type Input<'a> = Hardrive;
+
+    const DAY: u8 = 9;
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
+        Hardrive::from_str(input)
     }
 
     // TODO maybe come back and improve the performance of part1
-    fn part1(&self, input: &str) -> String {
-        let mut hd = Hardrive::from_str(input).unwrap();
+    fn part1(&self, hd: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let mut hd = hd.clone();
         for id in (0..hd.len).rev() {
             hd.part1_fragment_once(id);
         }
-        hd.checksum().to_string()
+        Ok(hd.checksum().into())
     }
 
-    fn part2(&self, input: &str) -> String {
-        let mut hd = Hardrive::from_str(input).unwrap();
+    fn part2(&self, hd: &Self::Input<'_>) -> Result<Answer, AocError> {
+        let mut hd = hd.clone();
         for id in (0..(hd.last_id() + 1)).rev() {
             hd.part2_fragment_once(id);
         }
-        hd.checksum().to_string()
+        Ok(hd.checksum().into())
     }
 }
 
-#[derive(Debug)]
-struct Hardrive {
+#[derive(Debug, Clone)]
+pub struct Hardrive {
     // (Optional ID (None = Freespace), length)
     chunks: BTreeSet<Chunk>,
     pub len: usize,
@@ -188,7 +192,7 @@ impl Chunk {
 }
 
 impl FromStr for Hardrive {
-    type Err = String;
+    type Err = AocError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mut chunks_index_counter = 0;
@@ -199,7 +203,7 @@ impl FromStr for Hardrive {
             let size: usize = c
                 .to_string()
                 .parse()
-                .map_err(|_| format!("Failed to convert digit '{c}'"))?;
+                .map_err(|_| AocError::parse(0, index, format!("failed to convert digit '{c}'")))?;
 
             if !occupy {
                 chunks_index_counter += size;
@@ -245,24 +249,3 @@ impl Display for Hardrive {
         write!(f, "{s}")
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part1() {
-        const INPUT: &str = "2333133121414131402";
-
-        let results = Day.part1(INPUT);
-        assert_eq!(results, "1928");
-    }
-
-    #[test]
-    fn test_part2() {
-        const INPUT: &str = "2333133121414131402";
-
-        let results = Day.part2(INPUT);
-        assert_eq!(results, "2858");
-    }
-}
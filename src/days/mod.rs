@@ -1,13 +1,35 @@
+use crate::{AdventOfCodeDay, Solution};
 
-pub mod day1;
-pub mod day2;
-pub mod day3;
-pub mod day4;
-pub mod day5;
-pub mod day6;
-pub mod day7;
-pub mod day8;
-pub mod day9;
-pub mod day10;
-pub mod day11;
-pub mod day12;
+/// Declares each day's module and registers its `Day` in `DAYS`
+///
+/// Each day's `DAY` has to match its position in the list, so a duplicated, missing or misnumbered
+/// day fails to compile.
+macro_rules! days {
+    ($($module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Every solved day in order, `DAYS[n - 1]` is day `n`
+        pub static DAYS: &[&dyn Solution] = &[$(&$module::Day),*];
+
+        const _: () = {
+            let mut expected = 1;
+            $(
+                assert!(
+                    <$module::Day as AdventOfCodeDay>::DAY == expected,
+                    concat!(
+                        "`", stringify!($module), "` is out of order, days have to be registered ",
+                        "from day 1 without any gaps or duplicates"
+                    )
+                );
+                expected += 1;
+            )*
+        };
+    };
+}
+
+days![day1, day2, day3, day4, day5, day6, day7, day8, day9, day10, day11, day12];
+
+/// Looks up a day in the registry
+pub fn get(day: u8) -> Option<&'static dyn Solution> {
+    DAYS.get(usize::from(day).checked_sub(1)?).copied()
+}
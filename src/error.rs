@@ -0,0 +1,98 @@
+use std::{error::Error, fmt::Display, io, str::FromStr, time::Duration};
+
+use derive_more::derive::Display;
+
+/// Everything that can go wrong while reading, parsing or solving a day
+#[derive(Debug, Display)]
+pub enum AocError {
+    #[display("couldn't read '{path}': {source}")]
+    Io { path: String, source: io::Error },
+    /// `line` and `column` both start at 1 to match what an editor shows
+    #[display("parse error at line {line}, column {column}: {message}")]
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    #[display("unsupported input: {_0}")]
+    Unsupported(String),
+    /// A day or part panicked instead of returning an error, see `isolate`
+    #[display("panicked at {location}: {message}")]
+    Panicked { message: String, location: String },
+    /// An external solution's command couldn't be run or didn't print what was expected
+    #[display("external solution failed: {_0}")]
+    External(String),
+    /// `solve` was asked for a day or part that isn't registered
+    #[display("there's no solution for day {day} part {part}")]
+    NoSolution { day: u8, part: u8 },
+    /// A stage took longer than the `--timeout` given on the command line
+    #[display("timed out after {_0:?}")]
+    TimedOut(Duration),
+}
+
+impl AocError {
+    /// Creates a parse error using 0 based line and column indexes from `enumerate()`
+    pub fn parse(line_index: usize, column_index: usize, message: impl Into<String>) -> Self {
+        Self::Parse {
+            line: line_index + 1,
+            column: column_index + 1,
+            message: message.into(),
+        }
+    }
+
+    /// Moves a parse error onto the given 0 based line index, useful when a `FromStr` impl only
+    /// sees a single line and doesn't know where it is in the input
+    pub fn on_line(self, line_index: usize) -> Self {
+        match self {
+            AocError::Parse {
+                column, message, ..
+            } => AocError::Parse {
+                line: line_index + 1,
+                column,
+                message,
+            },
+            err => err,
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Parses `token` which has to be a slice of `line`, pointing at the token's position if it fails
+pub fn parse_token<T>(line_index: usize, line: &str, token: &str) -> Result<T, AocError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    token.parse().map_err(|err| {
+        let column_index = token.as_ptr() as usize - line.as_ptr() as usize;
+        AocError::parse(
+            line_index,
+            column_index,
+            format!("invalid value '{token}': {err}"),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_token_position() {
+        let line = "12 3x 4";
+        let err = parse_token::<u8>(2, line, &line[3..5]).unwrap_err();
+
+        let AocError::Parse { line, column, .. } = err.on_line(4) else {
+            panic!("expected a parse error");
+        };
+        assert_eq!((line, column), (5, 4));
+    }
+}
@@ -0,0 +1,478 @@
+//! Running solutions written in other languages.
+//!
+//! Any executable can be registered as the solver for some days in `solvers.toml`, with a table
+//! per solver:
+//!
+//! ```toml
+//! [python]
+//! command = "python3 python/solve.py"
+//! days = [1, 2, 3]
+//! ```
+//!
+//! The command is run once per part with `{day}` and `{input}` in its arguments replaced by the
+//! day number and the path of the input file. It's also sent a request on stdin:
+//!
+//! ```text
+//! day 1
+//! part 2
+//! input /tmp/aoc-python-day1-4242-0.txt
+//! ```
+//!
+//! The input is a temporary copy that's removed once the part finishes, its name can change so
+//! solvers should only ever read the path they're given.
+//!
+//! and has to print its answer on stdout, optionally with how long it took so process startup
+//! isn't counted:
+//!
+//! ```text
+//! answer 31
+//! time 1.2ms
+//! ```
+//!
+//! An empty `answer` means the part hasn't been solved yet, other lines are ignored and exiting
+//! with an error fails the part with whatever was printed to stderr.
+
+use std::{
+    env, fs,
+    io::Write,
+    path::PathBuf,
+    process::{Command, Stdio},
+    str::FromStr,
+    sync::atomic::{AtomicUsize, Ordering},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    bench::Samples,
+    runner::{PartReport, Parts},
+    Answer, AocError, PartSolver, Solution,
+};
+
+pub const SOLVERS_PATH: &str = "./solvers.toml";
+
+/// Runs the Nushell solutions from the root of the repo, what `aoc compare` checks against
+pub const DEFAULT_COMMAND: &str = "nu ./nu/runner.nu {day}";
+
+/// A command line with `{day}` and `{input}` placeholders, split on whitespace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalCommand {
+    program: String,
+    args: Vec<String>,
+}
+
+impl ExternalCommand {
+    /// Name of the program without its directory, used to label its results
+    pub fn name(&self) -> &str {
+        self.program.rsplit('/').next().unwrap_or(&self.program)
+    }
+
+    /// True if the command is given the input's path through an `{input}` placeholder
+    pub fn takes_input(&self) -> bool {
+        self.args.iter().any(|arg| arg.contains("{input}"))
+    }
+
+    /// Runs the command for a day with `stdin` as its input, returning its output and how long it
+    /// took to run
+    pub fn run(
+        &self,
+        day: u8,
+        input_path: Option<&str>,
+        stdin: &str,
+    ) -> Result<(String, Duration), AocError> {
+        let mut args = Vec::new();
+        for arg in &self.args {
+            let arg = arg.replace("{day}", &day.to_string());
+            if arg.contains("{input}") {
+                let Some(input_path) = input_path else {
+                    return Err(AocError::External(
+                        "{input} needs the input to come from a file".to_string(),
+                    ));
+                };
+                args.push(arg.replace("{input}", input_path));
+            } else {
+                args.push(arg);
+            }
+        }
+
+        let timer = Instant::now();
+        let mut child = Command::new(&self.program)
+            .args(&args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| AocError::External(format!("couldn't run {}: {err}", self.program)))?;
+        if let Some(mut pipe) = child.stdin.take() {
+            // Programs that don't read stdin are free to exit before it's written
+            let _ = pipe.write_all(stdin.as_bytes());
+        }
+        let output = child
+            .wait_with_output()
+            .map_err(|err| AocError::External(format!("couldn't run {}: {err}", self.program)))?;
+        let time = timer.elapsed();
+
+        if !output.status.success() {
+            return Err(AocError::External(format!(
+                "{} exited with {}: {}",
+                self.program,
+                output.status,
+                String::from_utf8_lossy(&output.stderr).trim()
+            )));
+        }
+
+        Ok((String::from_utf8_lossy(&output.stdout).into_owned(), time))
+    }
+}
+
+impl Default for ExternalCommand {
+    fn default() -> Self {
+        DEFAULT_COMMAND.parse().unwrap()
+    }
+}
+
+impl FromStr for ExternalCommand {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut words = s.split_whitespace().map(str::to_string);
+        let program = words.next().ok_or("The external command is empty")?;
+
+        Ok(Self {
+            program,
+            args: words.collect(),
+        })
+    }
+}
+
+/// A part's answer as printed by an external solution
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalPart {
+    pub answer: Answer,
+    /// The time the solution reported for itself, if any
+    pub time: Option<Duration>,
+}
+
+/// Parses times like `12ms`, `0.52 ms` or `1sec` the way Rust and Nushell print them
+pub(crate) fn parse_time(time: &str) -> Option<Duration> {
+    let time = time.trim();
+    let split = time
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(time.len());
+    let (number, unit) = time.split_at(split);
+    let number: f64 = number.parse().ok()?;
+
+    let seconds_per_unit = match unit.trim() {
+        "ns" => 1e-9,
+        "µs" | "us" => 1e-6,
+        "ms" => 1e-3,
+        "s" | "sec" => 1.0,
+        "min" => 60.0,
+        _ => return None,
+    };
+    Duration::try_from_secs_f64(number * seconds_per_unit).ok()
+}
+
+/// Reads the `answer` and `time` lines a solver printed in response to a request
+pub fn parse_response(output: &str) -> Result<ExternalPart, AocError> {
+    let mut answer = None;
+    let mut time = None;
+
+    for line in output.lines() {
+        let (key, value) = line.trim().split_once(' ').unwrap_or((line.trim(), ""));
+        match key {
+            "answer" => {
+                answer = Some(match value.trim() {
+                    "" => Answer::Unsolved,
+                    value => {
+                        let Ok(answer) = value.parse();
+                        answer
+                    }
+                })
+            }
+            "time" => {
+                time = Some(parse_time(value).ok_or_else(|| {
+                    AocError::External(format!("invalid time '{}'", value.trim()))
+                })?)
+            }
+            _ => {}
+        }
+    }
+
+    let answer = answer.ok_or_else(|| AocError::External("no answer was printed".to_string()))?;
+    Ok(ExternalPart { answer, time })
+}
+
+/// Solves a single day by running an external command, works anywhere a Rust day does
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExternalSolver {
+    name: String,
+    day: u8,
+    command: ExternalCommand,
+}
+
+impl ExternalSolver {
+    pub fn new(name: &str, day: u8, command: ExternalCommand) -> Self {
+        Self {
+            name: name.to_string(),
+            day,
+            command,
+        }
+    }
+
+    /// Asks the solver for a part's answer, using the time it reported or how long the whole
+    /// command took if it didn't report one
+    fn request(&self, part: u8, input: &InputFile) -> (Result<Answer, AocError>, Duration) {
+        let path = input.0.to_string_lossy();
+        let request = format!("day {}\npart {part}\ninput {path}\n", self.day);
+
+        match self
+            .command
+            .run(self.day, Some(&path), &request)
+            .and_then(|(output, wall_time)| Ok((parse_response(&output)?, wall_time)))
+        {
+            Ok((part, wall_time)) => (Ok(part.answer), part.time.unwrap_or(wall_time)),
+            Err(err) => (Err(err), Duration::ZERO),
+        }
+    }
+}
+
+impl Solution for ExternalSolver {
+    fn day(&self) -> u8 {
+        self.day
+    }
+
+    fn language(&self) -> &str {
+        &self.name
+    }
+
+    /// External solvers parse the input as part of each part, so the parse time is always zero
+    fn parsed<'a>(&'a self, input: &'a str) -> Result<(Duration, PartSolver<'a>), AocError> {
+        let input = InputFile::create(&self.name, self.day, input)?;
+
+        let solve_part = move |part| {
+            let (answer, time) = self.request(part, &input);
+            PartReport { answer, time }
+        };
+
+        Ok((Duration::ZERO, Box::new(solve_part)))
+    }
+
+    fn sample(&self, input: &str, iterations: usize, parts: Parts) -> Result<Samples, AocError> {
+        let input = InputFile::create(&self.name, self.day, input)?;
+        let mut samples: Samples = Default::default();
+
+        for _ in 0..iterations {
+            for part in parts.iter() {
+                let (answer, time) = self.request(part, &input);
+                answer?;
+                samples[part as usize].push(time);
+            }
+        }
+
+        Ok(samples)
+    }
+}
+
+/// The input written to a temporary file for a solver to read, removed once it's dropped
+struct InputFile(PathBuf);
+
+impl InputFile {
+    fn create(name: &str, day: u8, input: &str) -> Result<Self, AocError> {
+        // Unique per call as a timed out part can still be holding on to an earlier file
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let count = COUNT.fetch_add(1, Ordering::Relaxed);
+        let path = env::temp_dir().join(format!(
+            "aoc-{name}-day{day}-{}-{count}.txt",
+            std::process::id()
+        ));
+
+        fs::write(&path, input).map_err(|source| AocError::Io {
+            path: path.to_string_lossy().into_owned(),
+            source,
+        })?;
+        Ok(Self(path))
+    }
+}
+
+impl Drop for InputFile {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+/// Loads the days solved by the solver called `name` from the solvers file
+pub fn load_solvers(path: &str, name: &str) -> Result<Vec<ExternalSolver>, AocError> {
+    let contents = fs::read_to_string(path).map_err(|source| AocError::Io {
+        path: path.to_string(),
+        source,
+    })?;
+    let solvers = parse_solvers(&contents, name)?;
+    if solvers.is_empty() {
+        return Err(AocError::Unsupported(format!(
+            "no solver called '{name}' with any days in {path}"
+        )));
+    }
+    Ok(solvers)
+}
+
+/// Same as `load_solvers` but leaked so the solvers can be used in place of `days::DAYS`, they're
+/// needed for the rest of the program anyway
+pub fn load_solutions(
+    path: &str,
+    name: &str,
+) -> Result<&'static [&'static dyn Solution], AocError> {
+    let solutions: Vec<&'static dyn Solution> = load_solvers(path, name)?
+        .into_iter()
+        .map(|solver| -> &'static dyn Solution { Box::leak(Box::new(solver)) })
+        .collect();
+    Ok(solutions.leak())
+}
+
+/// Parses the `[name]` table of a solvers file into a solver per day, sorted by day
+fn parse_solvers(s: &str, name: &str) -> Result<Vec<ExternalSolver>, AocError> {
+    let mut table = None;
+    let mut command = None;
+    let mut days = Vec::new();
+
+    for (line_index, line) in s.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[') {
+            let header = header.strip_suffix(']').ok_or_else(|| {
+                AocError::parse(line_index, 0, format!("expected [name] but got {line}"))
+            })?;
+            table = Some(header.trim());
+            continue;
+        }
+
+        let Some(table) = table else {
+            return Err(AocError::parse(
+                line_index,
+                0,
+                "setting found before any [name]",
+            ));
+        };
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(AocError::parse(line_index, 0, "expected key = value"));
+        };
+        if table != name {
+            continue;
+        }
+
+        let value = value.trim();
+        match key.trim() {
+            "command" => {
+                let value = value
+                    .strip_prefix('"')
+                    .and_then(|value| value.strip_suffix('"'))
+                    .unwrap_or(value);
+                command = Some(
+                    value
+                        .parse::<ExternalCommand>()
+                        .map_err(|err| AocError::parse(line_index, 0, err))?,
+                );
+            }
+            "days" => {
+                days = value
+                    .strip_prefix('[')
+                    .and_then(|value| value.strip_suffix(']'))
+                    .ok_or_else(|| AocError::parse(line_index, 0, "expected days = [1, 2, ...]"))?
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|day| !day.is_empty())
+                    .map(|day| match day.parse() {
+                        Ok(day @ 1..=25) => Ok(day),
+                        _ => Err(AocError::parse(
+                            line_index,
+                            0,
+                            format!("invalid day '{day}', expected 1 to 25"),
+                        )),
+                    })
+                    .collect::<Result<_, _>>()?;
+            }
+            key => {
+                return Err(AocError::parse(
+                    line_index,
+                    0,
+                    format!("unknown key '{key}', expected command or days"),
+                ))
+            }
+        }
+    }
+
+    days.sort_unstable();
+    days.dedup();
+    if days.is_empty() {
+        return Ok(Vec::new());
+    }
+    let command = command.ok_or_else(|| {
+        AocError::Unsupported(format!("the '{name}' solver doesn't have a command"))
+    })?;
+
+    Ok(days
+        .into_iter()
+        .map(|day| ExternalSolver::new(name, day, command.clone()))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response("reading input\nanswer 31\ntime 1.2ms\n").unwrap(),
+            ExternalPart {
+                answer: Answer::Number(31),
+                time: Some(Duration::from_micros(1200)),
+            }
+        );
+        assert_eq!(
+            parse_response("answer\n").unwrap(),
+            ExternalPart {
+                answer: Answer::Unsolved,
+                time: None,
+            }
+        );
+        assert!(parse_response("answer 1\ntime soon").is_err());
+        assert!(parse_response("31").is_err());
+    }
+
+    #[test]
+    fn test_parse_solvers() {
+        const SOLVERS: &str = "# Solvers in other languages
+[python]
+command = \"python3 python/solve.py {day}\"
+days = [3, 1, 2]
+
+[ocaml]
+command = dune exec aoc
+days = [1]";
+
+        let solvers = parse_solvers(SOLVERS, "python").unwrap();
+        assert_eq!(
+            solvers.iter().map(|solver| solver.day).collect::<Vec<_>>(),
+            [1, 2, 3]
+        );
+        assert_eq!(solvers[0].command.args, ["python/solve.py", "{day}"]);
+        assert_eq!(solvers[0].language(), "python");
+
+        assert_eq!(parse_solvers(SOLVERS, "ocaml").unwrap().len(), 1);
+        assert!(parse_solvers(SOLVERS, "go").unwrap().is_empty());
+        assert!(parse_solvers("[python]\ndays = [26]", "python").is_err());
+        assert!(parse_solvers("[python]\ndays = [1]", "python").is_err());
+    }
+
+    #[test]
+    fn test_external_command() {
+        let command: ExternalCommand = "python3 solve.py --day {day} {input}".parse().unwrap();
+
+        assert_eq!(command.name(), "python3");
+        assert_eq!(command.args, ["solve.py", "--day", "{day}", "{input}"]);
+        assert!("  ".parse::<ExternalCommand>().is_err());
+    }
+}
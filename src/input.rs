@@ -0,0 +1,84 @@
+use std::{
+    env, fs,
+    io::{self, Read},
+    path::Path,
+};
+
+use crate::{AocError, Solution};
+
+/// Environment variable that changes where the `dayN.txt` input files are read from
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Directory holding `dayN.txt` inputs, `./inputs` unless `AOC_INPUT_DIR` is set
+pub fn input_dir() -> String {
+    env::var(INPUT_DIR_VAR).unwrap_or_else(|_| "./inputs".to_string())
+}
+
+/// Where to read a day's puzzle input from
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum InputSource {
+    /// `dayN.txt` inside of the input directory
+    #[default]
+    Default,
+    /// `--input <path>`, either a single input file or a directory of `dayN.txt` files
+    Path(String),
+    /// `--input -`
+    Stdin,
+    /// `--input-text <input>`
+    Inline(String),
+}
+
+impl InputSource {
+    pub fn from_flag(value: &str) -> Self {
+        match value {
+            "-" => InputSource::Stdin,
+            path => InputSource::Path(path.to_string()),
+        }
+    }
+
+    /// True if this source can only provide the input for a single day
+    pub fn is_single_input(&self) -> bool {
+        match self {
+            InputSource::Default => false,
+            InputSource::Path(path) => !Path::new(path).is_dir(),
+            InputSource::Stdin | InputSource::Inline(_) => true,
+        }
+    }
+
+    /// Path of the file holding a day's input, `None` when it doesn't come from a file
+    pub fn path(&self, day: u8) -> Option<String> {
+        match self {
+            InputSource::Default => Some(format!("{}/day{day}.txt", input_dir())),
+            InputSource::Path(path) if Path::new(path).is_dir() => {
+                Some(format!("{path}/day{day}.txt"))
+            }
+            InputSource::Path(path) => Some(path.clone()),
+            InputSource::Stdin | InputSource::Inline(_) => None,
+        }
+    }
+
+    pub fn read(&self, day: &dyn Solution) -> Result<String, AocError> {
+        match self {
+            InputSource::Default => day.read_input(),
+            InputSource::Path(path) if Path::new(path).is_dir() => {
+                read_file(format!("{path}/day{}.txt", day.day()))
+            }
+            InputSource::Path(path) => read_file(path.clone()),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|source| AocError::Io {
+                        path: "<stdin>".to_string(),
+                        source,
+                    })?;
+                Ok(input)
+            }
+            InputSource::Inline(input) => Ok(input.clone()),
+        }
+    }
+}
+
+pub fn read_file(path: String) -> Result<String, AocError> {
+    fs::read_to_string(&path).map_err(|source| AocError::Io { path, source })
+}
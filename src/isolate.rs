@@ -0,0 +1,85 @@
+//! Panic boundaries so one broken day doesn't take down the whole run.
+//!
+//! The default panic hook prints the message straight to stderr, so while inside `isolate` the
+//! hook instead remembers the message and location to be reported as an `AocError::Panicked`.
+//! Panics outside of `isolate` (like failing tests) still go to the default hook.
+
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+use crate::AocError;
+
+thread_local! {
+    static ISOLATING: Cell<bool> = const { Cell::new(false) };
+    static LAST_PANIC: RefCell<Option<AocError>> = const { RefCell::new(None) };
+}
+
+static INSTALL_HOOK: Once = Once::new();
+
+/// Runs `f` turning any panic into an `AocError::Panicked` error
+pub fn isolate<T>(f: impl FnOnce() -> Result<T, AocError>) -> Result<T, AocError> {
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !ISOLATING.get() {
+                return default_hook(info);
+            }
+            LAST_PANIC.set(Some(AocError::Panicked {
+                message: payload_message(info.payload()),
+                location: info
+                    .location()
+                    .map(|location| location.to_string())
+                    .unwrap_or_else(|| "unknown location".to_string()),
+            }));
+        }));
+    });
+
+    let was_isolating = ISOLATING.replace(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    ISOLATING.set(was_isolating);
+
+    result.unwrap_or_else(|payload| {
+        Err(LAST_PANIC.take().unwrap_or_else(|| AocError::Panicked {
+            message: payload_message(payload.as_ref()),
+            location: "unknown location".to_string(),
+        }))
+    })
+}
+
+/// `panic!` payloads are a `&str` for plain messages and a `String` when formatted
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_isolate() {
+        assert_eq!(isolate(|| Ok(3)).unwrap(), 3);
+
+        let result: Result<(), _> = isolate(|| panic!("day {} exploded", 12));
+        match result {
+            Err(AocError::Panicked { message, location }) => {
+                assert_eq!(message, "day 12 exploded");
+                assert!(location.starts_with("src/isolate.rs:"));
+            }
+            result => panic!("expected a panic error, got {result:?}"),
+        }
+
+        let values: Vec<u8> = Vec::new();
+        let result = isolate(|| Ok(values[0]));
+        assert!(matches!(result, Err(AocError::Panicked { .. })));
+    }
+}
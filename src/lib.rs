@@ -0,0 +1,155 @@
+//! Advent of Code 2024 solutions along with everything needed to run, verify and benchmark them.
+//!
+//! Each day implements `AdventOfCodeDay` and is registered in `days::DAYS`, `solve` is the simplest
+//! way to get an answer out of one.
+
+use std::time::{Duration, Instant};
+
+mod answer;
+mod baseline;
+pub mod bench;
+pub mod cache;
+pub mod compare;
+pub mod days;
+pub mod error;
+pub mod external;
+pub mod input;
+mod isolate;
+pub mod output;
+pub mod runner;
+pub mod status;
+pub mod verify;
+
+pub use answer::Answer;
+pub use error::AocError;
+
+use bench::Samples;
+use isolate::isolate;
+use runner::{PartReport, Parts};
+
+/// Parses the input and solves a single part of a day
+pub fn solve(day: u8, part: u8, input: &str) -> Result<Answer, AocError> {
+    let solution = days::get(day)
+        .filter(|_| matches!(part, 1 | 2))
+        .ok_or(AocError::NoSolution { day, part })?;
+
+    let (_, solve_part) = solution.parsed(input)?;
+    solve_part(part).answer
+}
+
+pub trait AdventOfCodeDay {
+    /// The parsed puzzle input that gets shared between both parts
+    type Input<'a>;
+
+    /// Day of the advent calendar this solves, `days!` checks it matches where the day is registered
+    const DAY: u8;
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError>;
+    fn part1(&self, input: &Self::Input<'_>) -> Result<Answer, AocError>;
+    fn part2(&self, input: &Self::Input<'_>) -> Result<Answer, AocError>;
+}
+
+/// Object safe version of `AdventOfCodeDay` so days with different `Input` types can be stored
+/// together, `Sync` so a day can be solved on a worker thread
+pub trait Solution: Sync {
+    fn day(&self) -> u8;
+
+    /// What the day is written in, used to label its results
+    fn language(&self) -> &str {
+        "Rust"
+    }
+
+    /// Parses the input returning how long it took along with a function that solves and times a
+    /// single part against the parsed input, panics in either are caught and returned as errors
+    fn parsed<'a>(&'a self, input: &'a str) -> Result<(Duration, PartSolver<'a>), AocError>;
+
+    /// Times parsing and the selected parts `iterations` times for benchmarking, skipped parts
+    /// have no samples
+    fn sample(&self, input: &str, iterations: usize, parts: Parts) -> Result<Samples, AocError>;
+
+    fn input_path(&self) -> String {
+        format!("{}/day{}.txt", input::input_dir(), self.day())
+    }
+
+    fn read_input(&self) -> Result<String, AocError> {
+        input::read_file(self.input_path())
+    }
+}
+
+/// Solves and times a single part of an already parsed input
+pub type PartSolver<'a> = Box<dyn Fn(u8) -> PartReport + 'a>;
+
+impl<T: AdventOfCodeDay + Sync> Solution for T {
+    fn day(&self) -> u8 {
+        T::DAY
+    }
+
+    fn parsed<'a>(&'a self, input: &'a str) -> Result<(Duration, PartSolver<'a>), AocError> {
+        let timer = Instant::now();
+        let input = isolate(|| self.parse(input))?;
+        let parse_time = timer.elapsed();
+
+        let solve_part = move |part| {
+            let timer = Instant::now();
+            let answer = isolate(|| match part {
+                1 => self.part1(&input),
+                _ => self.part2(&input),
+            });
+            PartReport {
+                answer,
+                time: timer.elapsed(),
+            }
+        };
+
+        Ok((parse_time, Box::new(solve_part)))
+    }
+
+    fn sample(&self, input: &str, iterations: usize, parts: Parts) -> Result<Samples, AocError> {
+        let mut samples: Samples = Default::default();
+
+        for _ in 0..iterations {
+            let timer = Instant::now();
+            let parsed = self.parse(input)?;
+            samples[0].push(timer.elapsed());
+
+            if parts.contains(1) {
+                let timer = Instant::now();
+                self.part1(&parsed)?;
+                samples[1].push(timer.elapsed());
+            }
+
+            if parts.contains(2) {
+                let timer = Instant::now();
+                self.part2(&parsed)?;
+                samples[2].push(timer.elapsed());
+            }
+        }
+
+        Ok(samples)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        const INPUT: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+        assert_eq!(solve(1, 1, INPUT).unwrap(), Answer::Number(11));
+        assert_eq!(solve(1, 2, INPUT).unwrap(), Answer::Number(31));
+        assert!(matches!(
+            solve(1, 3, INPUT),
+            Err(AocError::NoSolution { day: 1, part: 3 })
+        ));
+        assert!(matches!(
+            solve(26, 1, INPUT),
+            Err(AocError::NoSolution { .. })
+        ));
+    }
+}
@@ -1,59 +1,297 @@
-use std::{fs, time::Instant};
-
-mod days;
-
-use days::*;
-
-fn main() {
-    let days: Vec<Box<dyn AdventOfCodeDay>> = vec![
-        Box::new(day1::Day),
-        Box::new(day2::Day),
-        Box::new(day3::Day),
-        Box::new(day4::Day),
-        Box::new(day5::Day),
-        Box::new(day6::Day),
-        Box::new(day7::Day),
-        Box::new(day8::Day),
-        Box::new(day9::Day),
-        Box::new(day10::Day),
-        Box::new(day11::Day),
-        Box::new(day12::Day),
-    ];
-    let args = std::env::args().collect::<Vec<_>>();
-    let [_, day_selection] = args.as_slice() else {
-        eprintln!("Please select what day you want to run like: `cargo run -- <day>`");
-        return;
+use std::{
+    fs,
+    io::{self, Write},
+    process::ExitCode,
+    str::FromStr,
+    time::Duration,
+};
+
+mod cli;
+mod scaffold;
+
+use aoc::{
+    bench,
+    cache::{self, AnswerCache},
+    compare,
+    days::{
+        self,
+        day12::{render, report, Garden},
+    },
+    external,
+    input::InputSource,
+    output::{self, Format},
+    runner::{self, DayReport, DaySelection, Parts},
+    status,
+    verify::{self, Answers},
+    AocError, Solution,
+};
+use cli::{CacheMode, Cli, Command, GardenOutput};
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let Cli {
+        command,
+        input,
+        parts,
+        timeout,
+        solver,
+    } = match Cli::from_args(&args) {
+        Ok(cli) => cli,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::FAILURE;
+        }
     };
 
-    let Some(day) = days
-        .into_iter()
-        .find(|d| &d.day().to_string() == day_selection)
-    else {
-        eprintln!("Counldn't find day: '{day_selection}'");
-        return;
+    // External solvers stand in for the Rust days, only run, verify and bench accept one
+    let solutions = match &solver {
+        Some(name) => match external::load_solutions(external::SOLVERS_PATH, name) {
+            Ok(solutions) => solutions,
+            Err(err) => {
+                eprintln!("Failed to load the '{name}' solver: {err}");
+                return ExitCode::FAILURE;
+            }
+        },
+        None => days::DAYS,
     };
 
-    day.run_day();
+    match command {
+        Command::Run(DaySelection::Single(selected), options)
+            if options.format == Format::Text && options.cache == CacheMode::Off =>
+        {
+            let Some(day) = solutions.iter().copied().find(|d| d.day() == selected) else {
+                eprintln!("Counldn't find day: '{selected}'");
+                return ExitCode::FAILURE;
+            };
+
+            match input
+                .read(day)
+                .and_then(|input| runner::run_day(day, &input, parts, timeout))
+            {
+                Ok(()) => ExitCode::SUCCESS,
+                Err(err) => {
+                    eprintln!("{} Day {selected} failed: {err}", day.language());
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Run(selection, options) => {
+            let reports = match options.cache {
+                CacheMode::Off => run_selection(solutions, &selection, &input, parts, timeout),
+                cache => run_cached(
+                    &selection,
+                    &input,
+                    parts,
+                    timeout,
+                    cache == CacheMode::Refresh,
+                ),
+            };
+            let Some(reports) = reports else {
+                return ExitCode::FAILURE;
+            };
+
+            match options.format {
+                Format::Text => runner::print_table(&reports),
+                Format::Json => println!("{}", output::to_json(&reports)),
+                Format::Csv => println!("{}", output::to_csv(&reports)),
+            }
+
+            exit_code(reports.iter().all(DayReport::succeeded))
+        }
+        Command::Verify(selection) => {
+            let answers = match Answers::load(verify::ANSWERS_PATH) {
+                Ok(answers) => answers,
+                Err(err) => {
+                    eprintln!("Failed to load answers: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let Some(reports) = run_selection(solutions, &selection, &input, parts, timeout) else {
+                return ExitCode::FAILURE;
+            };
+
+            exit_code(verify::verify(&reports, &answers))
+        }
+        Command::Bench(selection, options) => {
+            let selected: Vec<_> = solutions
+                .iter()
+                .copied()
+                .filter(|d| selection.contains(d.day()))
+                .collect();
+            if selected.is_empty() {
+                eprintln!("No days found in: '{selection}'");
+                return ExitCode::FAILURE;
+            }
+
+            exit_code(bench::bench(&selected, &options, &input, parts))
+        }
+        Command::Compare(selection, options) => {
+            if let Err(err) = compare::check_input(&options.command, &input) {
+                eprintln!("Failed to compare: {err}");
+                return ExitCode::FAILURE;
+            }
+            let Some(reports) = run_selection(days::DAYS, &selection, &input, parts, timeout)
+            else {
+                return ExitCode::FAILURE;
+            };
+
+            exit_code(compare::compare(&reports, &options.command, &input))
+        }
+        Command::Status(options) => {
+            let answers = match Answers::load(verify::ANSWERS_PATH) {
+                Ok(answers) => answers,
+                Err(err) => {
+                    eprintln!("Failed to load answers: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let readme = options.readme.as_deref().unwrap_or(status::README_PATH);
+            let previous = match status::load_previous(readme) {
+                Ok(previous) => previous,
+                Err(err) => {
+                    eprintln!("Failed to read the current status table: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let reports: Vec<_> = days::DAYS
+                .iter()
+                .map(|day| DayReport::run(*day, &input, Parts::Both, timeout))
+                .collect();
+            let table = status::markdown_table(&status::statuses(&reports, &answers, &previous));
+            println!("{table}");
+
+            let Some(path) = options.readme else {
+                return ExitCode::SUCCESS;
+            };
+            match status::update_readme(&path, &table) {
+                Ok(()) => {
+                    println!("Updated the status table in {path}");
+                    ExitCode::SUCCESS
+                }
+                Err(err) => {
+                    eprintln!("Failed to update {path}: {err}");
+                    ExitCode::FAILURE
+                }
+            }
+        }
+        Command::Garden(options) => {
+            let garden = days::get(12)
+                .ok_or(AocError::NoSolution { day: 12, part: 1 })
+                .and_then(|day| input.read(day))
+                .and_then(|input| Garden::from_str(&input));
+            let garden = match garden {
+                Ok(garden) => garden,
+                Err(err) => {
+                    eprintln!("Failed to read the garden: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+
+            let output = match options.output {
+                GardenOutput::Svg => render::svg(&garden, options.labels).into_bytes(),
+                GardenOutput::Ppm => render::ppm(&garden),
+                GardenOutput::Report => {
+                    let plots = report::breakdown(&garden);
+                    let report = match options.format {
+                        Format::Json => report::json(&plots),
+                        _ => report::table(&plots),
+                    };
+                    format!("{report}\n").into_bytes()
+                }
+            };
+            write_output(options.path.as_deref(), &output)
+        }
+        Command::New(day) => match scaffold::new_day(day) {
+            Ok(()) => ExitCode::SUCCESS,
+            Err(err) => {
+                eprintln!("Couldn't create day {day}: {err}");
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
+
+/// Runs the selected parts of every day in the selection, `None` if the selection didn't match any
+/// days
+fn run_selection(
+    days: &[&'static dyn Solution],
+    selection: &DaySelection,
+    input: &InputSource,
+    parts: Parts,
+    timeout: Option<Duration>,
+) -> Option<Vec<DayReport>> {
+    let reports: Vec<_> = days
+        .iter()
+        .filter(|d| selection.contains(d.day()))
+        .map(|d| DayReport::run(*d, input, parts, timeout))
+        .collect();
+
+    if reports.is_empty() {
+        eprintln!("No days found in: '{selection}'");
+        return None;
+    }
+
+    Some(reports)
+}
+
+/// Same as `run_selection` for the Rust days but going through the answer cache, which is saved
+/// afterwards
+fn run_cached(
+    selection: &DaySelection,
+    input: &InputSource,
+    parts: Parts,
+    timeout: Option<Duration>,
+    refresh: bool,
+) -> Option<Vec<DayReport>> {
+    let mut cache = match AnswerCache::binary_version()
+        .and_then(|version| AnswerCache::load(cache::CACHE_PATH, version))
+    {
+        Ok(cache) => cache,
+        Err(err) => {
+            eprintln!("Failed to load the answer cache: {err}");
+            return None;
+        }
+    };
+
+    let reports: Vec<_> = days::DAYS
+        .iter()
+        .filter(|d| selection.contains(d.day()))
+        .map(|d| DayReport::run_cached(*d, input, parts, timeout, &mut cache, refresh))
+        .collect();
+
+    if reports.is_empty() {
+        eprintln!("No days found in: '{selection}'");
+        return None;
+    }
+
+    // The answers are still worth showing even if they couldn't be saved
+    if let Err(err) = cache.save(cache::CACHE_PATH) {
+        eprintln!("Failed to save the answer cache: {err}");
+    }
+
+    Some(reports)
+}
+
+/// Writes to the file at `path` or stdout if there isn't one
+fn write_output(path: Option<&str>, output: &[u8]) -> ExitCode {
+    let result = match path {
+        Some(path) => fs::write(path, output),
+        None => io::stdout().write_all(output),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Failed to write to {}: {err}", path.unwrap_or("stdout"));
+            ExitCode::FAILURE
+        }
+    }
 }
 
-pub trait AdventOfCodeDay {
-    fn day(&self) -> u8;
-    fn part1(&self, input: &str) -> String;
-    fn part2(&self, input: &str) -> String;
-
-    fn run_day(&self) {
-        let input = fs::read_to_string(format!("./inputs/day{}.txt", self.day()))
-            .expect("Failed to read input file");
-
-        let timer = Instant::now();
-        let part1 = self.part1(&input);
-        let p1_time = timer.elapsed();
-        let timer = Instant::now();
-        let part2 = self.part2(&input);
-        let p2_time = timer.elapsed();
-
-        println!("Rust Day {} results:", self.day());
-        println!("  Part 1: {part1} in {}ms", p1_time.as_millis());
-        println!("  Part 2: {part2} in {}ms", p2_time.as_millis());
+fn exit_code(success: bool) -> ExitCode {
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
     }
 }
@@ -0,0 +1,281 @@
+//! Machine readable output of run results.
+//!
+//! Each day produces a row per part with the day, part, answer, parse and solve times in
+//! nanoseconds, a status of `solved`, `cached`, `unsolved`, `error`, `panicked` or `timeout` and
+//! the error message if there was one. Cached answers weren't timed so they have no times.
+
+use std::{str::FromStr, time::Duration};
+
+use crate::{runner::DayReport, Answer, AocError};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    #[default]
+    Text,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!("Unknown format '{s}', expected text, json or csv")),
+        }
+    }
+}
+
+/// A single part's result flattened out for serialising
+struct Row<'a> {
+    day: u8,
+    part: u8,
+    answer: Option<&'a Answer>,
+    parse_time: Option<Duration>,
+    solve_time: Option<Duration>,
+    status: &'static str,
+    error: Option<String>,
+}
+
+fn rows(reports: &[DayReport]) -> Vec<Row<'_>> {
+    let mut rows = Vec::new();
+    for report in reports {
+        for part in report.parts.iter() {
+            let row = match &report.solved {
+                Ok(solved) => {
+                    let Some(part_report) = &solved.parts[part as usize - 1] else {
+                        continue;
+                    };
+                    let (answer, status, error) = match &part_report.answer {
+                        Ok(Answer::Unsolved) => (None, "unsolved", None),
+                        Ok(answer) if solved.cached => (Some(answer), "cached", None),
+                        Ok(answer) => (Some(answer), "solved", None),
+                        Err(err) => (None, status(err), Some(err.to_string())),
+                    };
+                    Row {
+                        day: report.day,
+                        part,
+                        answer,
+                        parse_time: Some(solved.parse_time).filter(|_| !solved.cached),
+                        solve_time: Some(part_report.time).filter(|_| !solved.cached),
+                        status,
+                        error,
+                    }
+                }
+                Err(err) => Row {
+                    day: report.day,
+                    part,
+                    answer: None,
+                    parse_time: None,
+                    solve_time: None,
+                    status: status(err),
+                    error: Some(err.to_string()),
+                },
+            };
+            rows.push(row);
+        }
+    }
+    rows
+}
+
+fn status(err: &AocError) -> &'static str {
+    match err {
+        AocError::Panicked { .. } => "panicked",
+        AocError::TimedOut(_) => "timeout",
+        _ => "error",
+    }
+}
+
+/// Serialises the reports as a JSON array of objects, one per part
+pub fn to_json(reports: &[DayReport]) -> String {
+    let nanos = |time: Option<Duration>| match time {
+        Some(time) => time.as_nanos().to_string(),
+        None => "null".to_string(),
+    };
+
+    let objects: Vec<String> = rows(reports)
+        .iter()
+        .map(|row| {
+            let answer = match row.answer {
+                Some(Answer::Number(number)) => number.to_string(),
+                Some(answer) => json_string(&answer.to_string()),
+                None => "null".to_string(),
+            };
+            let error = match &row.error {
+                Some(error) => json_string(error),
+                None => "null".to_string(),
+            };
+            format!(
+                r#"  {{"day": {}, "part": {}, "answer": {answer}, "parse_ns": {}, "solve_ns": {}, "status": "{}", "error": {error}}}"#,
+                row.day,
+                row.part,
+                nanos(row.parse_time),
+                nanos(row.solve_time),
+                row.status,
+            )
+        })
+        .collect();
+
+    format!("[\n{}\n]", objects.join(",\n"))
+}
+
+/// Serialises the reports as CSV with a header row, one row per part
+pub fn to_csv(reports: &[DayReport]) -> String {
+    let nanos = |time: Option<Duration>| time.map(|t| t.as_nanos().to_string()).unwrap_or_default();
+
+    let mut lines = vec!["day,part,answer,parse_ns,solve_ns,status,error".to_string()];
+    for row in rows(reports) {
+        lines.push(format!(
+            "{},{},{},{},{},{},{}",
+            row.day,
+            row.part,
+            csv_field(&row.answer.map(Answer::to_string).unwrap_or_default()),
+            nanos(row.parse_time),
+            nanos(row.solve_time),
+            row.status,
+            csv_field(&row.error.unwrap_or_default()),
+        ));
+    }
+    lines.join("\n")
+}
+
+pub(crate) fn json_string(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len() + 2);
+    escaped.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{PartReport, Parts, SolveReport};
+
+    fn reports() -> Vec<DayReport> {
+        vec![
+            DayReport {
+                day: 1,
+                parts: Parts::Both,
+                solved: Ok(SolveReport {
+                    parse_time: Duration::from_nanos(10),
+                    parts: [
+                        Some(PartReport {
+                            answer: Ok(Answer::Number(11)),
+                            time: Duration::from_nanos(20),
+                        }),
+                        Some(PartReport {
+                            answer: Ok(Answer::Unsolved),
+                            time: Duration::from_nanos(30),
+                        }),
+                    ],
+                    cached: false,
+                }),
+            },
+            DayReport {
+                day: 2,
+                parts: Parts::Both,
+                solved: Err(AocError::Unsupported("bad, \"input\"".into())),
+            },
+        ]
+    }
+
+    #[test]
+    fn test_json() {
+        assert_eq!(
+            to_json(&reports()),
+            r#"[
+  {"day": 1, "part": 1, "answer": 11, "parse_ns": 10, "solve_ns": 20, "status": "solved", "error": null},
+  {"day": 1, "part": 2, "answer": null, "parse_ns": 10, "solve_ns": 30, "status": "unsolved", "error": null},
+  {"day": 2, "part": 1, "answer": null, "parse_ns": null, "solve_ns": null, "status": "error", "error": "unsupported input: bad, \"input\""},
+  {"day": 2, "part": 2, "answer": null, "parse_ns": null, "solve_ns": null, "status": "error", "error": "unsupported input: bad, \"input\""}
+]"#
+        );
+    }
+
+    #[test]
+    fn test_csv() {
+        assert_eq!(
+            to_csv(&reports()),
+            r#"day,part,answer,parse_ns,solve_ns,status,error
+1,1,11,10,20,solved,
+1,2,,10,30,unsolved,
+2,1,,,,error,"unsupported input: bad, ""input"""
+2,2,,,,error,"unsupported input: bad, ""input""""#
+        );
+    }
+
+    #[test]
+    fn test_cached() {
+        let reports = vec![DayReport {
+            day: 6,
+            parts: Parts::Both,
+            solved: Ok(SolveReport {
+                parse_time: Duration::ZERO,
+                parts: [
+                    Some(PartReport {
+                        answer: Ok(Answer::Number(41)),
+                        time: Duration::ZERO,
+                    }),
+                    Some(PartReport {
+                        answer: Ok(Answer::Number(6)),
+                        time: Duration::ZERO,
+                    }),
+                ],
+                cached: true,
+            }),
+        }];
+
+        assert_eq!(
+            to_csv(&reports),
+            "day,part,answer,parse_ns,solve_ns,status,error\n6,1,41,,,cached,\n6,2,6,,,cached,"
+        );
+        assert!(to_json(&reports)
+            .contains(r#""answer": 41, "parse_ns": null, "solve_ns": null, "status": "cached""#));
+    }
+
+    #[test]
+    fn test_single_part() {
+        let reports = vec![DayReport {
+            day: 3,
+            parts: Parts::Only(2),
+            solved: Ok(SolveReport {
+                parse_time: Duration::from_nanos(10),
+                parts: [
+                    None,
+                    Some(PartReport {
+                        answer: Ok(Answer::Number(48)),
+                        time: Duration::from_nanos(20),
+                    }),
+                ],
+                cached: false,
+            }),
+        }];
+
+        assert_eq!(
+            to_csv(&reports),
+            "day,part,answer,parse_ns,solve_ns,status,error\n3,2,48,10,20,solved,"
+        );
+    }
+}
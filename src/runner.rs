@@ -0,0 +1,523 @@
+use std::{
+    fmt::Display,
+    ops::RangeInclusive,
+    str::FromStr,
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+use crate::{
+    cache::{self, AnswerCache},
+    input::InputSource,
+    Answer, AocError, Solution,
+};
+
+/// Which days the user asked to run from the command line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DaySelection {
+    /// `aoc 3`
+    Single(u8),
+    /// `aoc 3..8` or `aoc 3..=8`, both ends are included
+    Range(RangeInclusive<u8>),
+    /// `aoc all`
+    All,
+}
+
+impl DaySelection {
+    pub fn contains(&self, day: u8) -> bool {
+        match self {
+            DaySelection::Single(selected) => *selected == day,
+            DaySelection::Range(range) => range.contains(&day),
+            DaySelection::All => true,
+        }
+    }
+}
+
+impl Display for DaySelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DaySelection::Single(day) => write!(f, "{day}"),
+            DaySelection::Range(range) => write!(f, "{}..{}", range.start(), range.end()),
+            DaySelection::All => write!(f, "all"),
+        }
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "all" {
+            return Ok(Self::All);
+        }
+
+        let parse_day = |day: &str| {
+            day.parse::<u8>()
+                .map_err(|_| format!("Invalid day '{day}' in selection '{s}'"))
+        };
+
+        if let Some((start, end)) = s.split_once("..") {
+            let end = end.strip_prefix('=').unwrap_or(end);
+            let start = parse_day(start)?;
+            let end = parse_day(end)?;
+            if start > end {
+                return Err(format!("Range '{s}' is empty"));
+            }
+            return Ok(Self::Range(start..=end));
+        }
+
+        parse_day(s).map(Self::Single)
+    }
+}
+
+/// Which parts of a day to run, `aoc 12 --part 2` or `aoc 12.2` only runs part 2
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Parts {
+    #[default]
+    Both,
+    Only(u8),
+}
+
+impl Parts {
+    pub fn contains(self, part: u8) -> bool {
+        match self {
+            Parts::Both => true,
+            Parts::Only(only) => only == part,
+        }
+    }
+
+    /// The selected part numbers in order
+    pub fn iter(self) -> impl Iterator<Item = u8> {
+        (1..=2).filter(move |part| self.contains(*part))
+    }
+}
+
+impl FromStr for Parts {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "1" => Ok(Parts::Only(1)),
+            "2" => Ok(Parts::Only(2)),
+            _ => Err(format!("Invalid part '{s}', expected 1 or 2")),
+        }
+    }
+}
+
+/// Result of a single part along with how long it took to solve
+pub struct PartReport {
+    pub answer: Result<Answer, AocError>,
+    pub time: Duration,
+}
+
+/// Time it took to parse the input along with the results of both parts, `None` for a part that
+/// wasn't selected
+pub struct SolveReport {
+    pub parse_time: Duration,
+    pub parts: [Option<PartReport>; 2],
+    /// The answers came from the cache so nothing was timed
+    pub cached: bool,
+}
+
+/// Everything that happened when running a day, `Err` holds the reason it couldn't run
+pub struct DayReport {
+    pub day: u8,
+    pub parts: Parts,
+    pub solved: Result<SolveReport, AocError>,
+}
+
+impl DayReport {
+    pub fn run(
+        day: &'static dyn Solution,
+        input: &InputSource,
+        parts: Parts,
+        timeout: Option<Duration>,
+    ) -> Self {
+        Self {
+            day: day.day(),
+            parts,
+            solved: input
+                .read(day)
+                .and_then(|input| solve_report(day, &input, parts, timeout)),
+        }
+    }
+
+    /// Same as `run` but uses the answers in `cache` if every selected part is in there, adding
+    /// any newly solved answers to it. `refresh` ignores what's cached and solves the day again
+    pub fn run_cached(
+        day: &'static dyn Solution,
+        input: &InputSource,
+        parts: Parts,
+        timeout: Option<Duration>,
+        cache: &mut AnswerCache,
+        refresh: bool,
+    ) -> Self {
+        let solve = |input: String| {
+            let input_hash = cache::hash(input.as_bytes());
+            let cached: Option<Vec<_>> = parts
+                .iter()
+                .map(|part| Some((part, cache.get(day.day(), part, input_hash)?.clone())))
+                .collect();
+
+            if let (false, Some(cached)) = (refresh, cached) {
+                let mut report = SolveReport {
+                    parse_time: Duration::ZERO,
+                    parts: [None, None],
+                    cached: true,
+                };
+                for (part, answer) in cached {
+                    report.parts[part as usize - 1] = Some(PartReport {
+                        answer: Ok(answer),
+                        time: Duration::ZERO,
+                    });
+                }
+                return Ok(report);
+            }
+
+            let report = solve_report(day, &input, parts, timeout)?;
+            for (part, part_report) in (1..).zip(&report.parts) {
+                if let Some(PartReport {
+                    answer: Ok(answer), ..
+                }) = part_report
+                {
+                    cache.insert(day.day(), part, input_hash, answer);
+                }
+            }
+            Ok(report)
+        };
+
+        Self {
+            day: day.day(),
+            parts,
+            solved: input.read(day).and_then(solve),
+        }
+    }
+
+    /// True if the input was read, parsed and the selected parts solved without errors
+    pub fn succeeded(&self) -> bool {
+        match &self.solved {
+            Ok(solved) => solved
+                .parts
+                .iter()
+                .flatten()
+                .all(|part| part.answer.is_ok()),
+            Err(_) => false,
+        }
+    }
+}
+
+/// Solves the selected parts collecting the results into a report
+fn solve_report(
+    day: &'static dyn Solution,
+    input: &str,
+    parts: Parts,
+    timeout: Option<Duration>,
+) -> Result<SolveReport, AocError> {
+    let mut report = SolveReport {
+        parse_time: Duration::ZERO,
+        parts: [None, None],
+        cached: false,
+    };
+    solve_day(day, input, parts, timeout, &mut |progress| match progress {
+        Progress::Parsed(time) => report.parse_time = time,
+        Progress::Solved(part, part_report) => report.parts[part as usize - 1] = Some(part_report),
+    })?;
+    Ok(report)
+}
+
+/// A stage of a day finishing
+pub enum Progress {
+    Parsed(Duration),
+    Solved(u8, PartReport),
+}
+
+/// Parses the input then solves the selected parts, calling `on_progress` as each stage finishes
+///
+/// With a timeout the day is solved on a worker thread and any stage taking longer than `timeout`
+/// is given up on. A part that times out is reported as `AocError::TimedOut` and the parts after it
+/// are solved on a fresh worker, the stuck thread is left running until the program exits since
+/// there's no way to stop it.
+pub fn solve_day(
+    day: &'static dyn Solution,
+    input: &str,
+    parts: Parts,
+    timeout: Option<Duration>,
+    on_progress: &mut dyn FnMut(Progress),
+) -> Result<(), AocError> {
+    let mut remaining: Vec<u8> = parts.iter().collect();
+    let Some(timeout) = timeout else {
+        return solve_parts(day, input, &remaining, on_progress);
+    };
+
+    let mut parse_reported = false;
+    while !remaining.is_empty() {
+        let (sender, receiver) = mpsc::channel();
+        let worker_input = input.to_string();
+        let worker_parts = remaining.clone();
+        thread::spawn(move || {
+            let result = solve_parts(day, &worker_input, &worker_parts, &mut |progress| {
+                let _ = sender.send(Ok(progress));
+            });
+            if let Err(err) = result {
+                let _ = sender.send(Err(err));
+            }
+        });
+
+        let mut parsing = true;
+        loop {
+            match receiver.recv_timeout(timeout) {
+                Ok(Ok(Progress::Parsed(time))) => {
+                    parsing = false;
+                    // A fresh worker parses again but only the first parse time is reported
+                    if !parse_reported {
+                        parse_reported = true;
+                        on_progress(Progress::Parsed(time));
+                    }
+                }
+                Ok(Ok(Progress::Solved(part, report))) => {
+                    remaining.retain(|remaining| *remaining != part);
+                    on_progress(Progress::Solved(part, report));
+                }
+                Ok(Err(err)) => return Err(err),
+                Err(RecvTimeoutError::Timeout) if parsing => {
+                    return Err(AocError::TimedOut(timeout));
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    let part = remaining.remove(0);
+                    on_progress(Progress::Solved(
+                        part,
+                        PartReport {
+                            answer: Err(AocError::TimedOut(timeout)),
+                            time: timeout,
+                        },
+                    ));
+                    break;
+                }
+                // The worker finished every part
+                Err(RecvTimeoutError::Disconnected) => return Ok(()),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn solve_parts(
+    day: &dyn Solution,
+    input: &str,
+    parts: &[u8],
+    on_progress: &mut dyn FnMut(Progress),
+) -> Result<(), AocError> {
+    let (parse_time, solve_part) = day.parsed(input)?;
+    on_progress(Progress::Parsed(parse_time));
+    for part in parts {
+        on_progress(Progress::Solved(*part, solve_part(*part)));
+    }
+    Ok(())
+}
+
+/// Solves a single day printing each part as soon as it's solved, so a part that never finishes
+/// doesn't hide the other one
+pub fn run_day(
+    day: &'static dyn Solution,
+    input: &str,
+    parts: Parts,
+    timeout: Option<Duration>,
+) -> Result<(), AocError> {
+    let mut result = Ok(());
+    solve_day(day, input, parts, timeout, &mut |progress| match progress {
+        Progress::Parsed(time) => {
+            println!("{} Day {} results:", day.language(), day.day());
+            println!("  Parsed in {}ms", time.as_millis());
+        }
+        Progress::Solved(number, part) => {
+            let answer = match &part.answer {
+                Ok(answer) => answer.to_string(),
+                Err(err) => format!("failed, {err}"),
+            };
+            println!("  Part {number}: {answer} in {}ms", part.time.as_millis());
+
+            if let (Ok(()), Err(err)) = (&result, part.answer) {
+                result = Err(err);
+            }
+        }
+    })?;
+
+    result
+}
+
+/// Prints all the reports as one table with the columns lined up
+pub fn print_table(reports: &[DayReport]) {
+    let rows: Vec<Vec<String>> = reports
+        .iter()
+        .map(|report| {
+            let mut row = vec![report.day.to_string()];
+            match &report.solved {
+                Ok(solved) => {
+                    // Cached answers weren't timed so the times would only be misleading
+                    let time = |time: Duration| {
+                        if solved.cached {
+                            "cached".to_string()
+                        } else {
+                            format!("{time:.2?}")
+                        }
+                    };
+                    row.push(time(solved.parse_time));
+                    for part in &solved.parts {
+                        match part {
+                            Some(part) => {
+                                row.push(match &part.answer {
+                                    Ok(answer) => answer.to_string(),
+                                    Err(err) => status(err).to_string(),
+                                });
+                                row.push(time(part.time));
+                            }
+                            None => row.extend(["-".to_string(), "-".to_string()]),
+                        }
+                    }
+                }
+                Err(err @ (AocError::Panicked { .. } | AocError::TimedOut(_))) => {
+                    row.push(format!("{}, {err}", status(err)))
+                }
+                Err(err) => row.push(err.to_string()),
+            }
+            row
+        })
+        .collect();
+
+    print_aligned(&["Day", "Parse", "Part 1", "Time", "Part 2", "Time"], &rows);
+
+    // Errors are too long to fit in a cell so they are listed under the table
+    for report in reports {
+        let Ok(solved) = &report.solved else {
+            continue;
+        };
+        for (number, part) in solved.parts.iter().enumerate() {
+            if let Some(PartReport {
+                answer: Err(err), ..
+            }) = part
+            {
+                eprintln!("Day {} part {} failed: {err}", report.day, number + 1);
+            }
+        }
+    }
+}
+
+/// Short status shown in a table cell in place of an answer
+fn status(err: &AocError) -> &'static str {
+    match err {
+        AocError::Panicked { .. } => "FAILED",
+        AocError::TimedOut(_) => "TIMEOUT",
+        _ => "error",
+    }
+}
+
+/// Prints a table with every column padded to its widest cell
+///
+/// Rows with fewer cells than the header (like a day that failed) don't stretch the columns
+pub fn print_aligned(header: &[&str], rows: &[Vec<String>]) {
+    println!("{}", aligned(header, rows));
+}
+
+/// The table `print_aligned` prints, without a trailing newline
+pub fn aligned(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<_> = header.iter().map(|h| h.chars().count()).collect();
+    for row in rows.iter().filter(|row| row.len() == header.len()) {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |row: &[String]| {
+        row.iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join(" | ")
+            .trim_end()
+            .to_string()
+    };
+
+    let header: Vec<_> = header.iter().map(|h| h.to_string()).collect();
+    let mut lines = vec![
+        format_row(&header),
+        widths
+            .iter()
+            .map(|width| "-".repeat(*width))
+            .collect::<Vec<_>>()
+            .join("-|-"),
+    ];
+    lines.extend(rows.iter().map(|row| format_row(row)));
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AdventOfCodeDay;
+
+    struct Sleepy;
+
+    impl AdventOfCodeDay for Sleepy {
+        type Input<'a> = ();
+
+        const DAY: u8 = 0;
+
+        fn parse<'a>(&self, _input: &'a str) -> Result<Self::Input<'a>, AocError> {
+            Ok(())
+        }
+
+        fn part1(&self, _input: &Self::Input<'_>) -> Result<Answer, AocError> {
+            thread::sleep(Duration::from_secs(1));
+            Ok(1.into())
+        }
+
+        fn part2(&self, _input: &Self::Input<'_>) -> Result<Answer, AocError> {
+            Ok(2.into())
+        }
+    }
+
+    #[test]
+    fn test_parse_selection() {
+        assert_eq!("all".parse(), Ok(DaySelection::All));
+        assert_eq!("7".parse(), Ok(DaySelection::Single(7)));
+        assert_eq!("3..8".parse(), Ok(DaySelection::Range(3..=8)));
+        assert_eq!("3..=8".parse(), Ok(DaySelection::Range(3..=8)));
+        assert!("8..3".parse::<DaySelection>().is_err());
+        assert!("x".parse::<DaySelection>().is_err());
+    }
+
+    #[test]
+    fn test_parse_parts() {
+        assert_eq!("2".parse(), Ok(Parts::Only(2)));
+        assert!("3".parse::<Parts>().is_err());
+        assert_eq!(Parts::Only(2).iter().collect::<Vec<_>>(), vec![2]);
+        assert_eq!(Parts::Both.iter().collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_timeout() {
+        let mut answers = Vec::new();
+        let result = solve_day(
+            &Sleepy,
+            "",
+            Parts::Both,
+            Some(Duration::from_millis(50)),
+            &mut |progress| {
+                if let Progress::Solved(part, report) = progress {
+                    answers.push((part, report.answer.map_err(|err| err.to_string())));
+                }
+            },
+        );
+
+        assert!(result.is_ok());
+        assert_eq!(
+            answers,
+            vec![
+                (1, Err("timed out after 50ms".to_string())),
+                (2, Ok(Answer::Number(2)))
+            ]
+        );
+    }
+}
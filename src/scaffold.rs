@@ -0,0 +1,175 @@
+//! `aoc new <day>` which sets up everything needed to start on a new day.
+
+use std::{fs, path::Path};
+
+use aoc::input;
+
+const DAYS_DIR: &str = "./src/days";
+const EXAMPLES_DIR: &str = "./examples";
+
+const TEMPLATE: &str = r#"use crate::{AdventOfCodeDay, Answer, AocError};
+
+pub struct Day;
+
+impl AdventOfCodeDay for Day {
+    type Input<'a> = Vec<&'a str>;
+
+    const DAY: u8 = {day};
+
+    fn parse<'a>(&self, input: &'a str) -> Result<Self::Input<'a>, AocError> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(&self, _input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(Answer::Unsolved)
+    }
+
+    fn part2(&self, _input: &Self::Input<'_>) -> Result<Answer, AocError> {
+        Ok(Answer::Unsolved)
+    }
+}
+"#;
+
+const FIXTURE_ANSWERS: &str = "# Answers for example.txt, uncomment once known
+# part1 = 0
+# part2 = 0
+";
+
+/// Creates `src/days/dayN.rs`, registers it in `days!` and adds an empty input and example
+/// fixture, refusing to touch a day that already exists
+pub fn new_day(day: u8) -> Result<(), String> {
+    let source_path = format!("{DAYS_DIR}/day{day}.rs");
+    let registry_path = format!("{DAYS_DIR}/mod.rs");
+    if Path::new(&source_path).exists() {
+        return Err(format!("{source_path} already exists"));
+    }
+
+    let registry = read(&registry_path)?;
+    let registry = register(&registry, day)?;
+
+    write(&source_path, &TEMPLATE.replace("{day}", &day.to_string()))?;
+    println!("Created {source_path}");
+    write(&registry_path, &registry)?;
+    println!("Registered day {day} in {registry_path}");
+
+    // Inputs and examples might have been saved before starting on the code so they're kept
+    let input_path = format!("{}/day{day}.txt", input::input_dir());
+    create_if_missing(&input_path, "")?;
+    let fixture_path = format!("{EXAMPLES_DIR}/day{day}/example");
+    create_if_missing(&format!("{fixture_path}.txt"), "")?;
+    create_if_missing(&format!("{fixture_path}.toml"), FIXTURE_ANSWERS)?;
+
+    Ok(())
+}
+
+/// Adds `dayN` to the end of the `days![...]` list, it has to be the next day in order
+fn register(registry: &str, day: u8) -> Result<String, String> {
+    let start = registry
+        .find("days![")
+        .ok_or("couldn't find the days![...] list")?;
+    let end = start
+        + registry[start..]
+            .find("];")
+            .ok_or("couldn't find the end of the days![...] list")?;
+
+    let mut days: Vec<String> = registry[start + "days![".len()..end]
+        .split(',')
+        .map(|module| module.trim().to_string())
+        .filter(|module| !module.is_empty())
+        .collect();
+
+    let module = format!("day{day}");
+    if days.contains(&module) {
+        return Err(format!("Day {day} is already registered"));
+    }
+    if usize::from(day) != days.len() + 1 {
+        return Err(format!(
+            "Days have to be added in order, the next day is {}",
+            days.len() + 1
+        ));
+    }
+    days.push(module);
+
+    Ok(format!(
+        "{}{}{}",
+        &registry[..start],
+        format_days(&days),
+        &registry[end + "];".len()..]
+    ))
+}
+
+/// Lays out the list the same way rustfmt does, on one line if it fits otherwise packed onto
+/// indented lines
+fn format_days(days: &[String]) -> String {
+    const MAX_WIDTH: usize = 100;
+
+    let single_line = format!("days![{}];", days.join(", "));
+    if single_line.len() <= MAX_WIDTH {
+        return single_line;
+    }
+
+    let mut lines = Vec::new();
+    let mut line = String::from("   ");
+    for (index, day) in days.iter().enumerate() {
+        let separator = if index + 1 < days.len() { "," } else { "" };
+        if line.len() + 1 + day.len() + separator.len() > MAX_WIDTH {
+            lines.push(line);
+            line = String::from("   ");
+        }
+        line.push_str(&format!(" {day}{separator}"));
+    }
+    lines.push(line);
+
+    format!("days![\n{}\n];", lines.join("\n"))
+}
+
+fn read(path: &str) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("couldn't read '{path}': {err}"))
+}
+
+fn write(path: &str, contents: &str) -> Result<(), String> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent).map_err(|err| format!("couldn't create '{path}': {err}"))?;
+    }
+    fs::write(path, contents).map_err(|err| format!("couldn't write '{path}': {err}"))
+}
+
+fn create_if_missing(path: &str, contents: &str) -> Result<(), String> {
+    if Path::new(path).exists() {
+        println!("Kept existing {path}");
+        return Ok(());
+    }
+    write(path, contents)?;
+    println!("Created {path}");
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_register() {
+        let registry = "use crate::Solution;\n\ndays![day1, day2];\n\npub fn get() {}\n";
+
+        assert_eq!(
+            register(registry, 3).unwrap(),
+            "use crate::Solution;\n\ndays![day1, day2, day3];\n\npub fn get() {}\n"
+        );
+        assert!(register(registry, 2).is_err());
+        assert!(register(registry, 5).is_err());
+    }
+
+    #[test]
+    fn test_format_days() {
+        let days: Vec<_> = (1..=16).map(|day| format!("day{day}")).collect();
+
+        assert_eq!(
+            format_days(&days),
+            "days![
+    day1, day2, day3, day4, day5, day6, day7, day8, day9, day10, day11, day12, day13, day14, day15,
+    day16
+];"
+        );
+    }
+}
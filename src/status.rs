@@ -0,0 +1,293 @@
+//! The ✅/❌ table of completed days shown in the README.
+//!
+//! A Rust part counts as done once its answer matches the one in `answers.toml`, or when it
+//! returns an answer if there isn't one to check against. Without an input the part is checked
+//! against its examples instead, and parts with neither keep whatever mark the README already
+//! gives them. A Nushell part counts as done when `nu/dayN.nu` defines a `partN` command.
+
+use std::{collections::HashMap, fs, io};
+
+use crate::{
+    input,
+    runner::DayReport,
+    verify::{self, Answers, Verdict},
+    Answer, AocError,
+};
+
+pub const NU_DIR: &str = "./nu";
+pub const EXAMPLES_DIR: &str = "./examples";
+pub const README_PATH: &str = "./README.md";
+
+/// Marks the start of the generated table in the README, everything up to `END_MARKER` is replaced
+pub const START_MARKER: &str = "<!-- status:start -->";
+pub const END_MARKER: &str = "<!-- status:end -->";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayStatus {
+    pub day: u8,
+    pub rust: [bool; 2],
+    pub nushell: [bool; 2],
+}
+
+/// Works out the status of every day that has either a Rust or a Nushell solution, `previous`
+/// holds the README's Rust marks for parts that can't be checked at all
+pub fn statuses(
+    reports: &[DayReport],
+    answers: &Answers,
+    previous: &HashMap<u8, [bool; 2]>,
+) -> Vec<DayStatus> {
+    let nushell: Vec<(u8, [bool; 2])> = (1..=25)
+        .filter_map(|day| {
+            let source = fs::read_to_string(format!("{NU_DIR}/day{day}.nu")).ok()?;
+            Some((day, nushell_parts(&source)))
+        })
+        .collect();
+
+    let last_day = reports
+        .iter()
+        .map(|report| report.day)
+        .chain(nushell.iter().map(|(day, _)| *day))
+        .max()
+        .unwrap_or_default();
+
+    (1..=last_day)
+        .map(|day| {
+            let rust = match reports.iter().find(|report| report.day == day) {
+                Some(report) => [1, 2].map(|part| {
+                    let previous = previous
+                        .get(&day)
+                        .is_some_and(|marks| marks[part as usize - 1]);
+                    rust_done(report, part, answers, previous)
+                }),
+                None => [false; 2],
+            };
+            let nushell = nushell
+                .iter()
+                .find(|(nu_day, _)| *nu_day == day)
+                .map(|(_, parts)| *parts)
+                .unwrap_or_default();
+
+            DayStatus { day, rust, nushell }
+        })
+        .collect()
+}
+
+/// Whether a Rust part is done, `previous` is its current mark in the README
+fn rust_done(report: &DayReport, part: u8, answers: &Answers, previous: bool) -> bool {
+    let input_missing = matches!(
+        &report.solved,
+        Err(AocError::Io { source, .. }) if source.kind() == io::ErrorKind::NotFound
+    );
+    if input_missing {
+        return examples_pass(report.day, part).unwrap_or(previous);
+    }
+
+    match verify::check(report, part, answers) {
+        Some((Verdict::Pass, _)) => true,
+        // Nothing to check against, so any answer counts but errors and `Unsolved` don't
+        Some((Verdict::Unknown, _)) => report.solved.as_ref().is_ok_and(|solved| {
+            solved.parts[part as usize - 1]
+                .as_ref()
+                .is_some_and(|part| part.answer.as_ref().is_ok_and(|a| *a != Answer::Unsolved))
+        }),
+        _ => false,
+    }
+}
+
+/// Solves a part for each example in `examples/dayN/` that has an answer for it, returning
+/// whether they all matched or `None` if there weren't any
+fn examples_pass(day: u8, part: u8) -> Option<bool> {
+    let mut passed = None;
+    for entry in fs::read_dir(format!("{EXAMPLES_DIR}/day{day}"))
+        .ok()?
+        .flatten()
+    {
+        let path = entry.path();
+        if path.extension().is_none_or(|extension| extension != "txt") {
+            continue;
+        }
+        let expected = fs::read_to_string(path.with_extension("toml"))
+            .ok()
+            .and_then(|answers| Answers::parse_for_day(&answers, day).ok())
+            .and_then(|answers| answers.get(day, part).cloned());
+        let Some(expected) = expected else {
+            continue;
+        };
+
+        let answer = fs::read_to_string(&path)
+            .ok()
+            .and_then(|input| crate::solve(day, part, &input).ok());
+        passed = Some(passed.unwrap_or(true) && answer == Some(expected));
+    }
+    passed
+}
+
+/// Which of `part1` and `part2` a Nushell solution defines
+fn nushell_parts(source: &str) -> [bool; 2] {
+    ["part1", "part2"].map(|part| {
+        source.lines().any(|line| {
+            line.trim_start()
+                .strip_prefix("def ")
+                .is_some_and(|definition| definition.trim_start().starts_with(&format!("{part} ")))
+        })
+    })
+}
+
+/// The Rust marks of each day in the status section of a README
+pub fn previous_rust(readme: &str) -> HashMap<u8, [bool; 2]> {
+    let section = readme
+        .split_once(START_MARKER)
+        .and_then(|(_, rest)| rest.split_once(END_MARKER))
+        .map(|(section, _)| section)
+        .unwrap_or_default();
+
+    section
+        .lines()
+        .filter_map(|line| {
+            let cells: Vec<&str> = line.split('|').map(str::trim).collect();
+            let day = cells.get(1)?.parse().ok()?;
+            let marks: Vec<bool> = cells.get(2)?.chars().map(|mark| mark == '✅').collect();
+            Some((day, marks.try_into().ok()?))
+        })
+        .collect()
+}
+
+/// Loads the Rust marks from the README at `path`, a missing README has none
+pub fn load_previous(path: &str) -> Result<HashMap<u8, [bool; 2]>, AocError> {
+    match fs::read_to_string(path) {
+        Ok(readme) => Ok(previous_rust(&readme)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(HashMap::new()),
+        Err(source) => Err(AocError::Io {
+            path: path.to_string(),
+            source,
+        }),
+    }
+}
+
+/// Renders the statuses as a markdown table with a ✅ or ❌ for each part
+pub fn markdown_table(statuses: &[DayStatus]) -> String {
+    let marks = |parts: [bool; 2]| -> String {
+        parts
+            .iter()
+            .map(|done| if *done { "✅" } else { "❌" })
+            .collect()
+    };
+
+    // The emoji are two columns wide so a pair fills the four columns of "Rust"
+    let mut lines = vec![
+        "| Day | Rust | Nushell |".to_string(),
+        "| --- | ---- | ------- |".to_string(),
+    ];
+    for status in statuses {
+        lines.push(format!(
+            "| {:<3} | {} | {}    |",
+            status.day,
+            marks(status.rust),
+            marks(status.nushell)
+        ));
+    }
+    lines.join("\n")
+}
+
+/// Replaces everything between the status markers with `table`
+pub fn replace_section(readme: &str, table: &str) -> Result<String, AocError> {
+    let missing = || {
+        AocError::Unsupported(format!(
+            "expected the README to contain {START_MARKER} followed by {END_MARKER}"
+        ))
+    };
+    let start = readme.find(START_MARKER).ok_or_else(missing)? + START_MARKER.len();
+    let end = start + readme[start..].find(END_MARKER).ok_or_else(missing)?;
+
+    Ok(format!("{}\n{table}\n{}", &readme[..start], &readme[end..]))
+}
+
+/// Rewrites the status section of the README at `path` with `table`
+pub fn update_readme(path: &str, table: &str) -> Result<(), AocError> {
+    let readme = input::read_file(path.to_string())?;
+    let readme = replace_section(&readme, table)?;
+    fs::write(path, readme).map_err(|source| AocError::Io {
+        path: path.to_string(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_markdown_table() {
+        let statuses = [
+            DayStatus {
+                day: 1,
+                rust: [true, true],
+                nushell: [true, false],
+            },
+            DayStatus {
+                day: 12,
+                rust: [true, false],
+                nushell: [false, false],
+            },
+        ];
+
+        assert_eq!(
+            markdown_table(&statuses),
+            "| Day | Rust | Nushell |
+| --- | ---- | ------- |
+| 1   | ✅✅ | ✅❌    |
+| 12  | ✅❌ | ❌❌    |"
+        );
+    }
+
+    #[test]
+    fn test_previous_rust() {
+        let statuses = [
+            DayStatus {
+                day: 1,
+                rust: [true, true],
+                nushell: [false, false],
+            },
+            DayStatus {
+                day: 12,
+                rust: [true, false],
+                nushell: [true, true],
+            },
+        ];
+        let readme = format!(
+            "# Title\n{START_MARKER}\n{}\n{END_MARKER}\n| 2 | ✅✅ |",
+            markdown_table(&statuses)
+        );
+
+        assert_eq!(
+            previous_rust(&readme),
+            HashMap::from([(1, [true, true]), (12, [true, false])])
+        );
+        assert!(previous_rust("# Title").is_empty());
+    }
+
+    #[test]
+    fn test_examples_pass() {
+        assert_eq!(examples_pass(12, 2), Some(true));
+        assert_eq!(examples_pass(11, 2), None);
+    }
+
+    #[test]
+    fn test_nushell_parts() {
+        assert_eq!(
+            nushell_parts("def part1 [input: string] {\n}\n\ndef is_valid_part2 [] {}"),
+            [true, false]
+        );
+    }
+
+    #[test]
+    fn test_replace_section() {
+        let readme = format!("# Title\n{START_MARKER}\nold\n{END_MARKER}\nrest");
+
+        assert_eq!(
+            replace_section(&readme, "new").unwrap(),
+            format!("# Title\n{START_MARKER}\nnew\n{END_MARKER}\nrest")
+        );
+        assert!(replace_section("# Title", "new").is_err());
+    }
+}
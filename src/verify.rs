@@ -0,0 +1,225 @@
+//! Checks solutions against known good answers stored in `answers.toml`.
+//!
+//! The file uses a small subset of TOML, one table per day with a key per part:
+//!
+//! ```toml
+//! [day1]
+//! part1 = 11
+//! part2 = 31
+//!
+//! [day8]
+//! part1 = "text answers go in quotes"
+//! ```
+
+use std::{collections::HashMap, fs, io, str::FromStr};
+
+use derive_more::derive::Display;
+
+use crate::{runner::DayReport, Answer, AocError};
+
+pub const ANSWERS_PATH: &str = "./answers.toml";
+
+/// Known good answers keyed by `(day, part)`
+#[derive(Debug, Default)]
+pub struct Answers(HashMap<(u8, u8), Answer>);
+
+impl Answers {
+    /// Loads the answers file, a missing file just means no answers are known yet
+    pub fn load(path: &str) -> Result<Self, AocError> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents.parse(),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(source) => Err(AocError::Io {
+                path: path.to_string(),
+                source,
+            }),
+        }
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.0.get(&(day, part))
+    }
+
+    /// Parses answers that belong to `day` without needing a `[dayN]` table, like the answers
+    /// stored next to the example fixtures
+    pub fn parse_for_day(s: &str, day: u8) -> Result<Self, AocError> {
+        Self::parse_tables(s, Some(day))
+    }
+
+    fn parse_tables(s: &str, mut day: Option<u8>) -> Result<Self, AocError> {
+        let mut answers = HashMap::new();
+
+        for (line_index, line) in s.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(table) = line.strip_prefix('[') {
+                let number = table
+                    .strip_suffix(']')
+                    .and_then(|table| table.trim().strip_prefix("day"))
+                    .and_then(|number| number.parse::<u8>().ok())
+                    .ok_or_else(|| {
+                        AocError::parse(line_index, 0, format!("expected [dayN] but got {line}"))
+                    })?;
+                day = Some(number);
+                continue;
+            }
+
+            let Some(day) = day else {
+                return Err(AocError::parse(
+                    line_index,
+                    0,
+                    "answer found before any [dayN]",
+                ));
+            };
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(AocError::parse(line_index, 0, "expected partN = answer"));
+            };
+
+            let part = match key.trim() {
+                "part1" => 1,
+                "part2" => 2,
+                key => {
+                    return Err(AocError::parse(
+                        line_index,
+                        0,
+                        format!("unknown key '{key}', expected part1 or part2"),
+                    ))
+                }
+            };
+
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+                .unwrap_or(value);
+            let Ok(answer) = value.parse();
+            answers.insert((day, part), answer);
+        }
+
+        Ok(Self(answers))
+    }
+}
+
+impl FromStr for Answers {
+    type Err = AocError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_tables(s, None)
+    }
+}
+
+/// Outcome of checking a single part
+#[derive(Debug, PartialEq, Eq, Display)]
+pub enum Verdict {
+    #[display("PASS")]
+    Pass,
+    #[display("FAIL")]
+    Fail,
+    /// There's no known answer to check against
+    #[display("UNKNOWN")]
+    Unknown,
+}
+
+/// Checks a single part of a report against the known answers, returning the verdict along with
+/// what the part produced or `None` if the part wasn't run
+pub fn check(report: &DayReport, part: u8, answers: &Answers) -> Option<(Verdict, String)> {
+    let got = match &report.solved {
+        Ok(solved) => solved.parts[part as usize - 1]
+            .as_ref()?
+            .answer
+            .as_ref()
+            .map_err(AocError::to_string),
+        Err(err) => Err(err.to_string()),
+    };
+
+    // Errors only count as failures when there is a known answer that was missed
+    let verdict = match (answers.get(report.day, part), &got) {
+        (None, _) => Verdict::Unknown,
+        (Some(expected), Ok(answer)) if expected == *answer => Verdict::Pass,
+        (Some(_), _) => Verdict::Fail,
+    };
+    let got = match got {
+        Ok(answer) => answer.to_string(),
+        Err(err) => err,
+    };
+
+    Some((verdict, got))
+}
+
+/// Compares each part's answer with the known answers, printing a table of the results and
+/// returning true if nothing failed
+pub fn verify(reports: &[DayReport], answers: &Answers) -> bool {
+    let mut rows = Vec::new();
+    let mut passed = true;
+    let mut checked = false;
+
+    for report in reports {
+        for part in report.parts.iter() {
+            let Some((verdict, got)) = check(report, part, answers) else {
+                continue;
+            };
+
+            passed &= verdict != Verdict::Fail;
+            checked |= verdict != Verdict::Unknown;
+            rows.push(vec![
+                report.day.to_string(),
+                part.to_string(),
+                verdict.to_string(),
+                answers
+                    .get(report.day, part)
+                    .map(Answer::to_string)
+                    .unwrap_or_default(),
+                got,
+            ]);
+        }
+    }
+
+    crate::runner::print_aligned(&["Day", "Part", "Status", "Expected", "Got"], &rows);
+    if !checked {
+        eprintln!("No answers are known for these parts, add them to {ANSWERS_PATH} to check them");
+    }
+
+    passed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answers() {
+        const INPUT: &str = "# Known answers
+[day1]
+part1 = 11
+part2 = \"31\"
+
+[day8]
+part1 = \"abc\" # a comment";
+
+        let answers = Answers::from_str(INPUT).unwrap();
+
+        assert_eq!(answers.get(1, 1), Some(&Answer::Number(11)));
+        assert_eq!(answers.get(1, 2), Some(&Answer::Number(31)));
+        assert_eq!(answers.get(8, 1), Some(&Answer::Text("abc".into())));
+        assert_eq!(answers.get(8, 2), None);
+    }
+
+    #[test]
+    fn test_parse_answers_error() {
+        let err = Answers::from_str("[day1]\npart3 = 4").unwrap_err();
+
+        assert!(matches!(err, AocError::Parse { line: 2, .. }));
+    }
+
+    #[test]
+    fn test_parse_for_day() {
+        let answers = Answers::parse_for_day("part2 = 48", 3).unwrap();
+
+        assert_eq!(answers.get(3, 2), Some(&Answer::Number(48)));
+        assert!(Answers::from_str("part2 = 48").is_err());
+    }
+}
@@ -0,0 +1,54 @@
+//! Runs `aoc compare` with `tests/stubs/nushell.sh` standing in for the Nushell solutions.
+
+use std::process::{Command, Output};
+
+fn compare(day: u8, input: &str) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["compare", &day.to_string(), "--input", input])
+        .args(["--command", "sh tests/stubs/nushell.sh {day} {input}"])
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_answers_agree() {
+    let output = compare(1, "examples/day1/example.txt");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "{stdout}");
+    assert!(stdout.contains("| sh "), "{stdout}");
+    assert_eq!(stdout.matches("| yes").count(), 2, "{stdout}");
+}
+
+#[test]
+fn test_answers_disagree() {
+    let output = compare(3, "examples/day3/example1.txt");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(!output.status.success(), "{stdout}");
+    assert_eq!(stdout.matches("| NO").count(), 1, "{stdout}");
+    assert_eq!(stdout.matches("| missing").count(), 1, "{stdout}");
+}
+
+#[test]
+fn test_command_without_input() {
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["compare", "1", "--input", "examples/day1/example.txt"])
+        .args(["--command", "sh tests/stubs/nushell.sh {day}"])
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("{input}"));
+}
+
+#[test]
+fn test_missing_answers() {
+    let output = compare(2, "examples/day2/example.txt");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "{stdout}");
+    assert_eq!(stdout.matches("| missing").count(), 2, "{stdout}");
+}
@@ -0,0 +1,34 @@
+//! Runs every example fixture in `examples/dayN/` against the registered days.
+//!
+//! A fixture is a `name.txt` input next to a `name.toml` holding the expected answers, either part
+//! can be left out when an example only applies to one of them and a fixture without any answers
+//! (like the stub from `aoc new`) is skipped:
+//!
+//! ```toml
+//! part1 = 140
+//! part2 = 80
+//! ```
+//!
+//! `build.rs` generates a test per fixture so adding a new example doesn't need any code.
+
+use std::fs;
+
+use aoc::verify::Answers;
+
+fn check_fixture(day: u8, name: &str) {
+    let path = format!("{}/examples/day{day}/{name}", env!("CARGO_MANIFEST_DIR"));
+    let input = fs::read_to_string(format!("{path}.txt")).unwrap();
+    let answers = fs::read_to_string(format!("{path}.toml"))
+        .unwrap_or_else(|err| panic!("couldn't read the answers for {path}.txt: {err}"));
+    let answers = Answers::parse_for_day(&answers, day).unwrap();
+
+    for part in [1, 2] {
+        let Some(expected) = answers.get(day, part) else {
+            continue;
+        };
+        let answer = aoc::solve(day, part, &input).unwrap();
+        assert_eq!(&answer, expected, "day {day} part {part} of {name}");
+    }
+}
+
+include!(concat!(env!("OUT_DIR"), "/examples.rs"));
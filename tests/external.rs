@@ -0,0 +1,87 @@
+//! Runs `tests/stubs/solver.sh` as an external solver, through the library and the binary.
+
+use std::{
+    env, fs,
+    process::{Command, Output},
+    time::Duration,
+};
+
+use aoc::{
+    external::{ExternalCommand, ExternalSolver},
+    Answer, AocError, Solution,
+};
+
+const INPUT: &str = "3   4
+4   3
+2   5
+1   3
+3   9
+3   3";
+
+fn stub(day: u8) -> ExternalSolver {
+    let command: ExternalCommand =
+        format!("sh {}/tests/stubs/solver.sh", env!("CARGO_MANIFEST_DIR"))
+            .parse()
+            .unwrap();
+    ExternalSolver::new("stub", day, command)
+}
+
+#[test]
+fn test_solver() {
+    let solver = stub(1);
+    assert_eq!(solver.language(), "stub");
+
+    let (_, solve_part) = solver.parsed(INPUT).unwrap();
+    let part1 = solve_part(1);
+    assert_eq!(part1.answer.unwrap(), Answer::Number(11));
+    assert_eq!(part1.time, Duration::from_micros(2500));
+    assert_eq!(solve_part(2).answer.unwrap(), Answer::Number(31));
+
+    let solver = stub(2);
+    let (_, solve_part) = solver.parsed(INPUT).unwrap();
+    assert!(matches!(solve_part(1).answer, Err(AocError::External(_))));
+}
+
+/// Runs the binary in a scratch directory holding a `solvers.toml` that registers the stub
+fn aoc(name: &str, args: &[&str]) -> Output {
+    let dir = env::temp_dir().join(format!("aoc-external-{name}-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("solvers.toml"),
+        format!(
+            "[stub]\ncommand = \"sh {}/tests/stubs/solver.sh\"\ndays = [1, 2]\n",
+            env!("CARGO_MANIFEST_DIR")
+        ),
+    )
+    .unwrap();
+    fs::write(dir.join("answers.toml"), "[day1]\npart1 = 11\npart2 = 31\n").unwrap();
+    fs::write(dir.join("day1.txt"), INPUT).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .current_dir(&dir)
+        .args(args)
+        .args(["--solver", "stub", "--input", "day1.txt"])
+        .output()
+        .unwrap();
+    fs::remove_dir_all(&dir).unwrap();
+    output
+}
+
+#[test]
+fn test_run_solver() {
+    let output = aoc("run", &["1"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "{stdout}");
+    assert!(stdout.contains("stub Day 1 results:"), "{stdout}");
+    assert!(stdout.contains("Part 2: 31"), "{stdout}");
+}
+
+#[test]
+fn test_verify_solver() {
+    let output = aoc("verify", &["verify", "1"]);
+    let stdout = String::from_utf8_lossy(&output.stdout);
+
+    assert!(output.status.success(), "{stdout}");
+    assert_eq!(stdout.matches("PASS").count(), 2, "{stdout}");
+}
@@ -0,0 +1,14 @@
+#!/bin/sh
+# Stands in for `nu ./nu/runner.nu {day}` in tests/compare.rs, it only knows day 1's example answers,
+# gets day 3 wrong and hasn't solved any other days
+if [ "$1" = 1 ]; then
+    echo "Nushell Day 1 results:"
+    echo "  Part1: 11 in 0.52 ms"
+    echo "  Part2: 31 in 1.2 ms"
+elif [ "$1" = 3 ]; then
+    echo "Nushell Day 3 results:"
+    echo "  Part1: 0 in 0.1 ms"
+    echo "  Part2:  in 0.1 ms"
+else
+    echo "Day $1 not found"
+fi
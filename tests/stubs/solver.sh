@@ -0,0 +1,23 @@
+#!/bin/sh
+# A solver speaking the external protocol for tests/external.rs, it only knows day 1's example
+# answers and fails any other day
+while read -r key value; do
+    case "$key" in
+        day) day=$value ;;
+        part) part=$value ;;
+        input) input=$value ;;
+    esac
+done
+
+if [ "$day" != 1 ] || [ ! -f "$input" ]; then
+    echo "can't solve day $day from '$input'" >&2
+    exit 1
+fi
+
+echo "reading $input"
+if [ "$part" = 1 ]; then
+    echo "answer 11"
+    echo "time 2.5ms"
+else
+    echo "answer 31"
+fi